@@ -0,0 +1,156 @@
+use std::{fs, path::Path, time::Duration};
+
+use anyhow::{bail, Context};
+use aoc23::{days::DAYS, Part};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{read_input, verify::load_answers};
+
+/// Only the real input is benchmarked.
+const INPUT: &str = "i";
+
+/// Timings of one stage (`parse`, `a` or `b`) of one day, in nanoseconds.
+#[derive(Debug, Serialize, Deserialize)]
+struct Stage {
+    day: u32,
+    stage: String,
+    input: String,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Report {
+    runs: usize,
+    stages: Vec<Stage>,
+}
+
+fn stage(day: u32, stage: String, mut samples: Vec<Duration>) -> Stage {
+    samples.sort();
+    Stage {
+        day,
+        stage,
+        input: INPUT.to_string(),
+        min_ns: samples[0].as_nanos() as u64,
+        median_ns: samples[samples.len() / 2].as_nanos() as u64,
+        max_ns: samples[samples.len() - 1].as_nanos() as u64,
+    }
+}
+
+/// Runs `day` `runs` times, timing parsing and each part separately, and returns the timings
+/// along with the parts that weren't timed.
+///
+/// Unless `all` is set, only the parts with a known answer for the real input are run, and answers
+/// marked `slow` are skipped, so that the benchmark is sure to finish.
+fn bench_day(day: u32, runs: usize, all: bool) -> anyhow::Result<(Vec<Stage>, Vec<Part>)> {
+    let (_, solution) = DAYS.iter().find(|&&(d, _)| d == day).unwrap();
+    let answers = load_answers(day)?;
+    let (parts, skipped): (Vec<Part>, Vec<Part>) =
+        solution.parts().iter().copied().partition(|&part| {
+            all || answers
+                .get(&(INPUT.to_string(), part))
+                .is_some_and(|answer| !answer.slow)
+        });
+    if parts.is_empty() {
+        return Ok((Vec::new(), skipped));
+    }
+
+    let input = read_input(day, INPUT)?;
+    let mut parse = Vec::new();
+    let mut solve = vec![Vec::new(); parts.len()];
+    for _ in 0..runs {
+        let timings = solution.time(&input, &parts)?;
+        parse.push(timings.parse);
+        for (samples, (_, time)) in solve.iter_mut().zip(timings.parts) {
+            samples.push(time);
+        }
+    }
+
+    let mut stages = vec![stage(day, "parse".to_string(), parse)];
+    for (part, samples) in parts.into_iter().zip(solve) {
+        stages.push(stage(day, part.to_string(), samples));
+    }
+    Ok((stages, skipped))
+}
+
+fn load_report(path: &Path) -> anyhow::Result<Report> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))?;
+    serde_json::from_str(&json).with_context(|| format!("couldn't parse {}", path.display()))
+}
+
+fn ns(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+/// Benchmarks `day`, or every day, printing a table of the timings.
+///
+/// The report is written to `json` as JSON if given. Medians are compared to those of a report
+/// previously written to `baseline`. Parts that aren't timed are listed as skipped, unless `all`
+/// asks for every part to be timed.
+pub fn bench(
+    day: Option<u32>,
+    runs: usize,
+    all: bool,
+    json: Option<&Path>,
+    baseline: Option<&Path>,
+) -> anyhow::Result<()> {
+    if runs == 0 {
+        bail!("need at least one run");
+    }
+    let days = DAYS
+        .iter()
+        .map(|&(d, _)| d)
+        .filter(|&d| day.is_none_or(|day| d == day))
+        .collect_vec();
+    if days.is_empty() {
+        bail!("no solution registered for day {}", day.unwrap());
+    }
+    let baseline = baseline.map(load_report).transpose()?;
+
+    let mut stages = Vec::new();
+    let mut skipped = Vec::new();
+    for day in days {
+        let (timed, untimed) = bench_day(day, runs, all)?;
+        stages.extend(timed);
+        skipped.extend(untimed.into_iter().map(|part| (day, part)));
+    }
+
+    println!(
+        "day stage {:>10} {:>10} {:>10} {:>10}",
+        "min", "median", "max", "baseline"
+    );
+    for stage in &stages {
+        let change = baseline
+            .iter()
+            .flat_map(|baseline| &baseline.stages)
+            .find(|b| (b.day, &b.stage, &b.input) == (stage.day, &stage.stage, &stage.input))
+            .map(|b| {
+                let change = stage.median_ns as f64 / b.median_ns as f64 - 1.0;
+                format!("{:+.1}%", change * 100.0)
+            })
+            .unwrap_or_default();
+        let line = format!(
+            "{:3} {:5} {:>10} {:>10} {:>10} {change:>10}",
+            stage.day,
+            stage.stage,
+            ns(stage.min_ns),
+            ns(stage.median_ns),
+            ns(stage.max_ns),
+        );
+        println!("{}", line.trim_end());
+    }
+    for (day, part) in skipped {
+        println!("{day:3} {:5} {:>10}", part.to_string(), "skipped");
+    }
+
+    if let Some(path) = json {
+        let report = Report { runs, stages };
+        fs::write(path, serde_json::to_string_pretty(&report)?)
+            .with_context(|| format!("couldn't write {}", path.display()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use aoc23::{
+    days::{DAYS, TOOLS},
+    read_stdin_to_string, Part,
+};
+use itertools::Itertools;
+
+mod bench;
+mod verify;
+
+pub(crate) fn input_path(day: u32, name: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "in", &day.to_string(), name]
+        .iter()
+        .collect()
+}
+
+pub(crate) fn read_input(day: u32, name: &str) -> anyhow::Result<String> {
+    if name == "-" {
+        return Ok(read_stdin_to_string());
+    }
+    let path = input_path(day, name);
+    std::fs::read_to_string(&path).with_context(|| format!("couldn't read {}", path.display()))
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = std::env::args().collect_vec();
+    let args = args.iter().map(|s| s.as_str()).collect_vec();
+
+    let is_part = |s: &str| s == "all" || s.parse::<Part>().is_ok();
+    let is_tool = |s: &str| TOOLS.iter().any(|&(_, t, _)| t == s);
+
+    if let ["verify", rest @ ..] = &args[1..] {
+        let (day, slow) = match rest {
+            [] => (None, false),
+            ["--slow"] => (None, true),
+            [day] => (Some(day), false),
+            [day, "--slow"] => (Some(day), true),
+            _ => bail!("usage: aoc verify [day] [--slow]"),
+        };
+        let day = day
+            .map(|day| day.parse().with_context(|| format!("invalid day: {day}")))
+            .transpose()?;
+        if !verify::verify(day, slow)? {
+            bail!("some answers didn't match");
+        }
+        return Ok(());
+    }
+
+    if let ["bench", rest @ ..] = &args[1..] {
+        const USAGE: &str =
+            "usage: aoc bench [day] [--runs N] [--json FILE] [--baseline FILE] [--all]";
+
+        let mut day = None;
+        let mut runs = 10;
+        let mut json = None;
+        let mut baseline = None;
+        let mut all = false;
+
+        let mut rest = rest.iter();
+        while let Some(&arg) = rest.next() {
+            let mut value = || rest.next().copied().context(USAGE);
+            match arg {
+                "--runs" => {
+                    let n = value()?;
+                    runs = n
+                        .parse()
+                        .with_context(|| format!("invalid run count: {n}"))?;
+                }
+                "--json" => json = Some(Path::new(value()?)),
+                "--baseline" => baseline = Some(Path::new(value()?)),
+                "--all" => all = true,
+                _ if day.is_none() => {
+                    day = Some(arg.parse().with_context(|| format!("invalid day: {arg}"))?)
+                }
+                _ => bail!(USAGE),
+            }
+        }
+
+        return bench::bench(day, runs, all, json, baseline);
+    }
+
+    if let [day, tool, rest @ ..] = &args[1..] {
+        if is_tool(tool) {
+            let day = day.parse().with_context(|| format!("invalid day: {day}"))?;
+            let Some(&(_, _, run)) = TOOLS.iter().find(|&&(d, t, _)| d == day && t == *tool) else {
+                bail!("no tool {tool} registered for day {day}");
+            };
+            let mut input = "i";
+            let mut tool_args = Vec::new();
+            let mut rest = rest.iter();
+            while let Some(&arg) = rest.next() {
+                match arg {
+                    "--input" => {
+                        input = rest
+                            .next()
+                            .copied()
+                            .context("--input needs an input name")?
+                    }
+                    _ => tool_args.push(arg),
+                }
+            }
+            let input = read_input(day, input)?;
+            println!("{}", run(&input, &tool_args)?.trim_end());
+            return Ok(());
+        }
+    }
+
+    let (day, part, input) = match &args[1..] {
+        [day] => (day, "all", "i"),
+        [day, part] if is_part(part) => (day, *part, "i"),
+        [day, input] => (day, "all", *input),
+        [day, part, input] => (day, *part, *input),
+        _ => bail!("usage: aoc <day> [a|b|all] [ex|i|test|...|-]\n       aoc <day> <tool> [--input ex|i|test|...|-] [args...]"),
+    };
+    let day = day.parse().with_context(|| format!("invalid day: {day}"))?;
+
+    let Some(&(_, solution)) = DAYS.iter().find(|&&(d, _)| d == day) else {
+        bail!("no solution registered for day {day}");
+    };
+    let parts = match part {
+        "all" => solution.parts().to_vec(),
+        part => match part.parse::<Part>() {
+            Ok(part) if solution.parts().contains(&part) => vec![part],
+            _ => bail!("no solution registered for day {day} part {part}"),
+        },
+    };
+
+    let input = read_input(day, input)?;
+    let mut failed = false;
+    for (part, answer) in solution.run(&input, &parts)? {
+        match answer {
+            Ok(answer) => println!("{part}: {answer}"),
+            Err(err) => {
+                println!("{part}: error: {err:#}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        bail!("some parts failed");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+};
+
+use anyhow::{bail, Context};
+use aoc23::{days::DAYS, Part};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use crate::{input_path, read_input};
+
+/// A known answer from `in/<day>/answers`.
+///
+/// Each line of that file reads `<input> <part> <answer>`, optionally followed by `slow` for runs
+/// that take too long to check by default. Blank lines and lines starting with `#` are ignored.
+pub(crate) struct Answer {
+    pub(crate) answer: String,
+    pub(crate) slow: bool,
+}
+
+pub(crate) fn load_answers(day: u32) -> anyhow::Result<FxHashMap<(String, Part), Answer>> {
+    let path = input_path(day, "answers");
+    let Ok(file) = fs::read_to_string(&path) else {
+        return Ok(Default::default());
+    };
+
+    let mut answers = FxHashMap::default();
+    for (i, line) in file.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let context = || format!("{}:{}", path.display(), i + 1);
+        let (input, part, answer, slow) = match *line.split_whitespace().collect_vec() {
+            [input, part, answer] => (input, part, answer, false),
+            [input, part, answer, "slow"] => (input, part, answer, true),
+            _ => bail!("{}: expected `<input> <part> <answer> [slow]`", context()),
+        };
+        let part = part.parse().with_context(context)?;
+
+        let answer = Answer {
+            answer: answer.to_string(),
+            slow,
+        };
+        if answers.insert((input.to_string(), part), answer).is_some() {
+            bail!("{}: duplicate answer for {input} {part}", context());
+        }
+    }
+
+    Ok(answers)
+}
+
+/// The inputs in `in/<day>/`, in name order.
+fn inputs(day: u32) -> anyhow::Result<Vec<String>> {
+    let dir = input_path(day, "");
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("couldn't read {}", dir.display()))? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name != "answers" {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+enum Status {
+    Pass,
+    Fail(String),
+    Missing,
+    Skipped,
+}
+
+struct Row {
+    day: u32,
+    part: Part,
+    input: String,
+    expected: Option<String>,
+    status: Status,
+}
+
+fn run(
+    day: u32,
+    input: &str,
+    parts: &[Part],
+) -> Result<Vec<(Part, anyhow::Result<String>)>, String> {
+    let (_, solution) = DAYS.iter().find(|&&(d, _)| d == day).unwrap();
+    let input = read_input(day, input).map_err(|err| err.to_string())?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input, parts))) {
+        Ok(Ok(answers)) => Ok(answers),
+        Ok(Err(err)) => Err(format!("error: {err}")),
+        Err(_) => Err("panicked".to_string()),
+    }
+}
+
+fn verify_day(day: u32, slow: bool) -> anyhow::Result<Vec<Row>> {
+    let (_, solution) = DAYS.iter().find(|&&(d, _)| d == day).unwrap();
+    let mut answers = load_answers(day)?;
+    let mut rows = Vec::new();
+
+    for input in inputs(day)? {
+        let expected = solution
+            .parts()
+            .iter()
+            .map(|&part| (part, answers.remove(&(input.clone(), part))))
+            .collect_vec();
+
+        let to_run = expected
+            .iter()
+            .filter(|(_, answer)| answer.as_ref().is_some_and(|answer| slow || !answer.slow))
+            .map(|&(part, _)| part)
+            .collect_vec();
+        let results = if to_run.is_empty() {
+            Ok(Vec::new())
+        } else {
+            run(day, &input, &to_run)
+        };
+
+        for (part, answer) in expected {
+            let status = match (&answer, &results) {
+                (None, _) => Status::Missing,
+                (Some(_), _) if !to_run.contains(&part) => Status::Skipped,
+                (Some(answer), Ok(results)) => {
+                    let (_, actual) = results.iter().find(|&&(p, _)| p == part).unwrap();
+                    match actual {
+                        Ok(actual) if *actual == answer.answer => Status::Pass,
+                        Ok(actual) => Status::Fail(actual.clone()),
+                        Err(err) => Status::Fail(format!("error: {err}")),
+                    }
+                }
+                (Some(_), Err(err)) => Status::Fail(err.clone()),
+            };
+            rows.push(Row {
+                day,
+                part,
+                input: input.clone(),
+                expected: answer.map(|answer| answer.answer),
+                status,
+            });
+        }
+    }
+
+    if let Some((input, part)) = answers.into_keys().min() {
+        bail!("in/{day}/answers: nothing to check for {input} {part}");
+    }
+
+    Ok(rows)
+}
+
+/// Checks the known answers of `day`, or of every day, printing a table of the results.
+///
+/// Returns whether every answer that was checked matched.
+pub fn verify(day: Option<u32>, slow: bool) -> anyhow::Result<bool> {
+    let days = DAYS
+        .iter()
+        .map(|&(d, _)| d)
+        .filter(|&d| day.is_none_or(|day| d == day))
+        .collect_vec();
+    if days.is_empty() {
+        bail!("no solution registered for day {}", day.unwrap());
+    }
+
+    let mut rows = Vec::new();
+    for day in days {
+        rows.extend(verify_day(day, slow)?);
+    }
+
+    let input_width = rows
+        .iter()
+        .map(|row| row.input.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let expected_width = rows
+        .iter()
+        .filter_map(|row| row.expected.as_ref().map(String::len))
+        .max()
+        .unwrap_or(0)
+        .max(8);
+
+    println!(
+        "day part {:input_width$} {:7} {:expected_width$} actual",
+        "input", "status", "expected"
+    );
+    for row in &rows {
+        let (status, actual) = match &row.status {
+            Status::Pass => ("pass", ""),
+            Status::Fail(actual) => ("FAIL", actual.as_str()),
+            Status::Missing => ("missing", ""),
+            Status::Skipped => ("skipped", ""),
+        };
+        let line = format!(
+            "{:3} {:4} {:input_width$} {status:7} {:expected_width$} {actual}",
+            row.day,
+            row.part,
+            row.input,
+            row.expected.as_deref().unwrap_or(""),
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let count = |f: fn(&Status) -> bool| rows.iter().filter(|row| f(&row.status)).count();
+    let failed = count(|s| matches!(s, Status::Fail(_)));
+    println!(
+        "\n{} passed, {failed} failed, {} missing, {} skipped",
+        count(|s| matches!(s, Status::Pass)),
+        count(|s| matches!(s, Status::Missing)),
+        count(|s| matches!(s, Status::Skipped)),
+    );
+
+    Ok(failed == 0)
+}
@@ -0,0 +1,116 @@
+//! The Chinese remainder theorem, generalized to moduli that aren't coprime.
+
+use num::{BigInt, Integer, Zero};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    #[error("the congruences have no common solution")]
+    NoSolution,
+    #[error("the smallest common solution doesn't fit in a u64")]
+    Overflow,
+}
+
+/// The values `x` with `x ≡ residue (mod modulus)`.
+///
+/// The modulus is wider than the residue, since combining congruences can push it past `u64`
+/// while the smallest solution still fits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: u64,
+    pub modulus: u128,
+}
+
+impl Congruence {
+    pub fn new(residue: u64, modulus: u64) -> Self {
+        assert_ne!(modulus, 0, "modulus must be positive");
+        Congruence {
+            residue: residue % modulus,
+            modulus: modulus.into(),
+        }
+    }
+
+    /// The values satisfying both congruences.
+    ///
+    /// Fails with [`CrtError::Overflow`] if the smallest of them doesn't fit in a `u64`, or the
+    /// combined modulus doesn't fit in a `u128`.
+    pub fn combine(self, other: Congruence) -> Result<Congruence, CrtError> {
+        let (r1, m1) = (BigInt::from(self.residue), BigInt::from(self.modulus));
+        let (r2, m2) = (BigInt::from(other.residue), BigInt::from(other.modulus));
+
+        let gcd = m1.extended_gcd(&m2);
+        let diff = r2 - &r1;
+        if !(&diff % &gcd.gcd).is_zero() {
+            return Err(CrtError::NoSolution);
+        }
+
+        // m1 * k ≡ r2 - r1 (mod m2), where gcd.x is the inverse of m1 / gcd modulo m2 / gcd.
+        let m2_g = m2 / &gcd.gcd;
+        let k = (diff / &gcd.gcd * gcd.x).mod_floor(&m2_g);
+        let residue = r1 + &m1 * k;
+        let modulus = m1 * m2_g;
+
+        Ok(Congruence {
+            residue: u64::try_from(residue).map_err(|_| CrtError::Overflow)?,
+            modulus: u128::try_from(modulus).map_err(|_| CrtError::Overflow)?,
+        })
+    }
+
+    /// The smallest value of at least `min` that satisfies the congruence.
+    pub fn first_at_least(self, min: u64) -> Result<u64, CrtError> {
+        if self.residue >= min {
+            return Ok(self.residue);
+        }
+        let (residue, below) = (self.residue as u128, min as u128 % self.modulus);
+        let ahead = if residue >= below {
+            residue - below
+        } else {
+            self.modulus - (below - residue)
+        };
+        (min as u128)
+            .checked_add(ahead)
+            .and_then(|first| u64::try_from(first).ok())
+            .ok_or(CrtError::Overflow)
+    }
+}
+
+/// The values satisfying every congruence.
+pub fn solve(congruences: impl IntoIterator<Item = Congruence>) -> Result<Congruence, CrtError> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), Congruence::combine)
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let c = |r, m| Congruence::new(r, m);
+
+    assert_eq!(solve([c(2, 3), c(3, 5), c(2, 7)]), Ok(c(23, 105)));
+    assert_eq!(solve([c(2, 4), c(4, 6)]), Ok(c(10, 12)));
+    assert_eq!(solve([c(1, 4), c(2, 6)]), Err(CrtError::NoSolution));
+    assert_eq!(solve([]), Ok(c(0, 1)));
+
+    let big = u64::MAX - 58; // the largest prime below 2^64
+    let wide = |r, m: u128| Congruence {
+        residue: r,
+        modulus: m,
+    };
+    let both = big as u128 * (big - 2) as u128;
+    assert_eq!(solve([c(3, big), c(3, big - 2)]), Ok(wide(3, both)));
+    assert_eq!(solve([c(3, big), c(2, big - 2)]), Err(CrtError::Overflow));
+    assert_eq!(solve([c(5, big), c(1, 2)]), Ok(wide(5, 2 * big as u128)));
+    assert_eq!(solve([c(60, big), c(1, 2)]), Err(CrtError::Overflow));
+    assert_eq!(solve([c(5, big), c(5, big)]), Ok(c(5, big)));
+    assert_eq!(
+        solve([c(0, big), c(0, big - 2), c(0, big - 4)]),
+        Err(CrtError::Overflow)
+    );
+
+    assert_eq!(c(10, 12).first_at_least(5), Ok(10));
+    assert_eq!(c(10, 12).first_at_least(11), Ok(22));
+    assert_eq!(c(10, 12).first_at_least(22), Ok(22));
+    assert_eq!(c(0, big).first_at_least(big), Ok(big));
+    assert_eq!(c(0, big).first_at_least(big + 1), Err(CrtError::Overflow));
+    assert_eq!(wide(3, both).first_at_least(4), Err(CrtError::Overflow));
+}
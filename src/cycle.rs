@@ -0,0 +1,102 @@
+//! Cycle detection for sequences `x0, f(x0), f(f(x0)), …` of a deterministic step function.
+
+use std::{collections::hash_map::Entry, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+/// Where a sequence starts repeating: the state at step `start + period` equals the one at `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`, which is always less than
+    /// `start + period`.
+    pub fn reduce(self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only keeps two states at a time.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Finds the cycle by remembering every state, stepping each one exactly once.
+pub fn hashed<S: Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen = FxHashMap::default();
+    let mut state = initial;
+    loop {
+        let len = seen.len();
+        match seen.entry(state) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Cycle {
+                    start,
+                    period: len - start,
+                };
+            }
+            Entry::Vacant(entry) => {
+                state = step(entry.key());
+                entry.insert(len);
+            }
+        }
+    }
+}
+
+/// The state at step `n`, without simulating more steps than it takes to find the cycle, and
+/// then to reach its first occurrence again.
+pub fn state_at<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = hashed(initial.clone(), &mut step);
+    (0..cycle.reduce(n)).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, …
+    let step = |&n: &u32| if n == 7 { 3 } else { n + 1 };
+    let cycle = Cycle {
+        start: 3,
+        period: 5,
+    };
+
+    assert_eq!(brent(0, step), cycle);
+    assert_eq!(hashed(0, step), cycle);
+
+    assert_eq!(state_at(0, step, 2), 2);
+    assert_eq!(state_at(0, step, 1_000_000_000), 5);
+    assert_eq!(brent(0, |&n: &u32| (n + 1) % 4).start, 0);
+}
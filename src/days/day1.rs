@@ -0,0 +1,102 @@
+use aho_corasick::AhoCorasick;
+use anyhow::Context;
+use itertools::{Itertools, MinMaxResult};
+
+use crate::Solution;
+
+/// Digits spelled out in English.
+pub const ENGLISH: &[(&str, u32)] = &[
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Reads the calibration value of a line: its first and last digit, as a two-digit number.
+pub struct Calibration {
+    automaton: AhoCorasick,
+    digits: Vec<u32>,
+}
+
+impl Calibration {
+    /// Digits may be written as numerals or as any of the given words.
+    pub fn new<'a>(words: impl IntoIterator<Item = &'a (&'a str, u32)>) -> Self {
+        let (patterns, digits): (Vec<String>, Vec<u32>) = (0..10)
+            .map(|d| (d.to_string(), d))
+            .chain(words.into_iter().map(|&(word, d)| (word.to_owned(), d)))
+            .unzip();
+
+        Calibration {
+            automaton: AhoCorasick::new(patterns).expect("too many words"),
+            digits,
+        }
+    }
+
+    /// Words may overlap, so the last digit of "eightwo" is 2.
+    pub fn value(&self, line: &str) -> Option<u32> {
+        let digits = self
+            .automaton
+            .find_overlapping_iter(line)
+            .map(|m| (m.start(), self.digits[m.pattern()]));
+
+        match digits.minmax_by_key(|&(start, _)| start) {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement((_, d)) => Some(d * 10 + d),
+            MinMaxResult::MinMax((_, first), (_, last)) => Some(first * 10 + last),
+        }
+    }
+
+    pub fn sum(&self, lines: &[&str]) -> anyhow::Result<u32> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                self.value(line)
+                    .with_context(|| format!("line {} has no digits", i + 1))
+            })
+            .sum()
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_a(lines: &Self::Input<'_>) -> anyhow::Result<u32> {
+        Calibration::new([]).sum(lines)
+    }
+
+    fn part_b(lines: &Self::Input<'_>) -> anyhow::Result<u32> {
+        Calibration::new(ENGLISH).sum(lines)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day1::parse(include_str!("../../in/1/ex")).unwrap();
+    assert_eq!(Day1::part_b(&ex).unwrap(), 281);
+    assert!(Day1::part_a(&ex).is_err());
+
+    let english = Calibration::new(ENGLISH);
+    assert_eq!(english.value("eightwo"), Some(82));
+    assert_eq!(english.value("xoneightx"), Some(18));
+    assert_eq!(english.value("7"), Some(77));
+    assert_eq!(english.value("abc"), None);
+    assert_eq!(Calibration::new([]).value("eightwo3"), Some(33));
+
+    let german = Calibration::new(&[("eins", 1), ("zwei", 2), ("drei", 3)]);
+    assert_eq!(german.value("zweinsx"), Some(21));
+    assert_eq!(german.value("one2drei"), Some(23));
+}
@@ -0,0 +1,311 @@
+use anyhow::{bail, ensure, Context};
+use chumsky::{prelude::*, text::newline};
+use itertools::Itertools;
+use strum::Display;
+
+use crate::{
+    chumsky_err, chumsky_parse,
+    render::{render, Highlight, Style},
+    Dir, Grid, Pos, Solution,
+};
+
+fn connections(pipe: u8) -> &'static [Dir] {
+    match pipe {
+        b'|' => &[Dir::Up, Dir::Down],
+        b'-' => &[Dir::Right, Dir::Left],
+        b'L' => &[Dir::Up, Dir::Right],
+        b'J' => &[Dir::Up, Dir::Left],
+        b'7' => &[Dir::Down, Dir::Left],
+        b'F' => &[Dir::Right, Dir::Down],
+        b'S' => &Dir::ALL,
+        b'.' => &[],
+        _ => unreachable!(),
+    }
+}
+
+fn parser() -> impl Parser<char, Grid<u8>, Error = Simple<char>> {
+    let square = one_of("|-LJ7F.S").map(|c| c as u8);
+
+    let row = square.repeated().at_least(1).then_ignore(newline());
+    row.repeated()
+        .at_least(1)
+        .then_ignore(end())
+        .map(Grid::from_rows)
+        .try_map(chumsky_err)
+}
+
+/// Replaces the start square with the pipe that connects it to the loop.
+fn resolve_start(mut grid: Grid<u8>) -> anyhow::Result<(Grid<u8>, Pos)> {
+    let starts = grid
+        .indexed_iter()
+        .filter(|&(_, &sq)| sq == b'S')
+        .map(|(pos, _)| Pos::from(pos))
+        .collect_vec();
+    let start = match *starts {
+        [start] => start,
+        [] => bail!("no start square"),
+        _ => bail!("{} start squares, expected one", starts.len()),
+    };
+
+    let start_dirs = Dir::ALL
+        .into_iter()
+        .filter(|&dir| {
+            start.step(dir, grid.shape()).is_some_and(|neighbor| {
+                grid[neighbor] != b'.' && connections(grid[neighbor]).contains(&dir.reverse())
+            })
+        })
+        .collect_vec();
+
+    grid[start] = match *start_dirs {
+        [Dir::Up, Dir::Down] => b'|',
+        [Dir::Right, Dir::Left] => b'-',
+        [Dir::Up, Dir::Right] => b'L',
+        [Dir::Up, Dir::Left] => b'J',
+        [Dir::Down, Dir::Left] => b'7',
+        [Dir::Right, Dir::Down] => b'F',
+        _ => bail!(
+            "the start square at {}:{} connects to {} pipes, not 2",
+            start.row,
+            start.col,
+            start_dirs.len()
+        ),
+    };
+
+    Ok((grid, start))
+}
+
+/// The order the loop is drawn in, as seen on screen.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum Winding {
+    Clockwise,
+    Counterclockwise,
+}
+
+/// The main loop, traced once from the start.
+#[derive(Debug, Clone)]
+struct Loop {
+    /// Every tile of the loop, in order, starting with the start tile.
+    path: Vec<Pos>,
+    /// Twice the area enclosed by the lines through the tile centres, negative if the loop runs
+    /// counterclockwise.
+    signed_area2: isize,
+}
+
+impl Loop {
+    fn trace(grid: &Grid<u8>, start: Pos) -> anyhow::Result<Self> {
+        let mut path = vec![start];
+        let mut dir = connections(grid[start])[0];
+        let mut pos = start;
+        loop {
+            pos = pos
+                .step(dir, grid.shape())
+                .context("the loop leaves the grid")?;
+            if pos == start {
+                break;
+            }
+            // A loop that never returns to the start would have to visit some tile twice.
+            ensure!(
+                path.len() < grid.nrows() * grid.ncols(),
+                "the loop never returns to the start"
+            );
+            let pipes = connections(grid[pos]);
+            ensure!(
+                pipes.contains(&dir.reverse()),
+                "the loop is broken at {}:{}, where {:?} doesn't connect back",
+                pos.row,
+                pos.col,
+                grid[pos] as char
+            );
+            path.push(pos);
+            dir = *pipes.iter().find(|&&next| next != dir.reverse()).unwrap();
+        }
+
+        // The shoelace formula, with x to the right and y downwards.
+        let signed_area2 = path
+            .iter()
+            .circular_tuple_windows()
+            .map(|(a, b)| a.col as isize * b.row as isize - b.col as isize * a.row as isize)
+            .sum();
+
+        Ok(Loop { path, signed_area2 })
+    }
+
+    fn len(&self) -> usize {
+        self.path.len()
+    }
+
+    /// The number of steps along the loop to the tile farthest from the start.
+    fn farthest(&self) -> usize {
+        self.len() / 2
+    }
+
+    fn winding(&self) -> Winding {
+        if self.signed_area2 > 0 {
+            Winding::Clockwise
+        } else {
+            Winding::Counterclockwise
+        }
+    }
+
+    /// The number of tiles inside the loop, by Pick's theorem: A = I + B/2 - 1, with every tile of
+    /// the loop a boundary point.
+    fn enclosed(&self) -> usize {
+        (self.signed_area2.unsigned_abs() + 2 - self.len()) / 2
+    }
+
+    fn tiles(&self, shape: (usize, usize)) -> Grid<bool> {
+        let mut on_loop = Grid::new(shape.0, shape.1, false);
+        for &pos in &self.path {
+            on_loop[pos] = true;
+        }
+        on_loop
+    }
+}
+
+/// Finds the tiles inside the loop by scanning down each column, tracking whether the left and
+/// right halves of the column are inside the loop.
+fn scanline_inside(grid: &Grid<u8>, on_loop: &Grid<bool>) -> anyhow::Result<Grid<bool>> {
+    let mut inside = grid.map(|_| false);
+
+    for (col, pipes) in grid.columns().enumerate() {
+        let mut l_inside = false;
+        let mut r_inside = false;
+        for (row, &pipe) in pipes.enumerate() {
+            if !on_loop[(row, col)] {
+                inside[(row, col)] = l_inside && r_inside;
+                continue;
+            }
+
+            // Whether the halves must agree before the pipe, and which of them it crosses.
+            let (agree, flip_l, flip_r) = match pipe {
+                b'|' => (false, false, false),
+                b'-' => (true, true, true),
+                b'L' => (false, false, true),
+                b'J' => (false, true, false),
+                b'7' => (true, true, false),
+                b'F' => (true, false, true),
+                _ => bail!("unexpected {:?} on the loop at {row}:{col}", pipe as char),
+            };
+            ensure!(
+                (l_inside == r_inside) == agree,
+                "the loop can't pass through {:?} at {row}:{col}",
+                pipe as char
+            );
+            l_inside ^= flip_l;
+            r_inside ^= flip_r;
+        }
+    }
+
+    Ok(inside)
+}
+
+/// Prints what a single trace of the loop finds, cross-checking the enclosed area against a
+/// scanline count.
+pub fn trace(input: &str, _args: &[&str]) -> anyhow::Result<String> {
+    let (grid, start) = Day10::parse(input)?;
+    let main_loop = Loop::trace(&grid, start)?;
+    let inside = scanline_inside(&grid, &main_loop.tiles(grid.shape()))?;
+    let scanned = inside.iter().filter(|&&b| b).count();
+    ensure!(
+        scanned == main_loop.enclosed(),
+        "the scanline count of {scanned} disagrees with Pick's theorem"
+    );
+
+    Ok(format!(
+        "length: {}\nfarthest: {}\nwinding: {}\nenclosed: {}",
+        main_loop.len(),
+        main_loop.farthest(),
+        main_loop.winding(),
+        main_loop.enclosed(),
+    ))
+}
+
+/// Draws the maze with box-drawing characters, highlighting the loop and the tiles inside and
+/// outside it. Pass `ascii` for plain ASCII instead.
+pub fn draw(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let style = match args {
+        [] => Style::Unicode,
+        ["ascii"] => Style::Ascii,
+        _ => bail!("expected no arguments or `ascii`"),
+    };
+
+    let (grid, start) = Day10::parse(input)?;
+    let on_loop = Loop::trace(&grid, start)?.tiles(grid.shape());
+    let inside = scanline_inside(&grid, &on_loop)?;
+
+    let highlight = |pos| {
+        if on_loop[pos] {
+            Highlight::Path
+        } else if inside[pos] {
+            Highlight::Inside
+        } else {
+            Highlight::Outside
+        }
+    };
+    Ok(render(&grid, |&pipe| connections(pipe), highlight, style))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = (Grid<u8>, Pos);
+
+    fn parse(input: &str) -> anyhow::Result<(Grid<u8>, Pos)> {
+        resolve_start(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a((grid, start): &Self::Input<'_>) -> anyhow::Result<usize> {
+        Ok(Loop::trace(grid, *start)?.farthest())
+    }
+
+    fn part_b((grid, start): &Self::Input<'_>) -> anyhow::Result<usize> {
+        Ok(Loop::trace(grid, *start)?.enclosed())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let inputs = [
+        include_str!("../../in/10/ex"),
+        include_str!("../../in/10/ex2"),
+        include_str!("../../in/10/ex3"),
+        include_str!("../../in/10/ex4"),
+        include_str!("../../in/10/ex5"),
+        include_str!("../../in/10/ex6"),
+        include_str!("../../in/10/ex7"),
+        include_str!("../../in/10/i"),
+    ];
+    for input in inputs {
+        let (grid, start) = Day10::parse(input).unwrap();
+        let main_loop = Loop::trace(&grid, start).unwrap();
+        let on_loop = main_loop.tiles(grid.shape());
+        assert_eq!(
+            scanline_inside(&grid, &on_loop)
+                .unwrap()
+                .iter()
+                .filter(|&&b| b)
+                .count(),
+            main_loop.enclosed()
+        );
+    }
+
+    let (grid, start) = Day10::parse(include_str!("../../in/10/ex")).unwrap();
+    let main_loop = Loop::trace(&grid, start).unwrap();
+    assert_eq!((main_loop.len(), main_loop.farthest()), (8, 4));
+    assert_eq!(main_loop.winding(), Winding::Clockwise);
+
+    assert!(Day10::parse(".F7\n.LJ\n").is_err());
+    assert!(Day10::parse("S7.\nLS.\n").is_err());
+    assert!(Day10::parse("S-7\n|.|\nL-J\n").is_ok());
+    assert!(Day10::parse("S-7\n|.S\nL-J\n").is_err());
+    assert!(Day10::parse("S|.\n...\n").is_err());
+
+    let broken = Day10::parse("S-7\n|.|\nL-.\n").unwrap();
+    assert!(Day10::part_a(&broken).is_err());
+    let side_cycle = Day10::parse(".....\nS-77.\n|.LJ.\nL....\n").unwrap();
+    assert!(Day10::part_b(&side_cycle).is_err());
+    let tight = Day10::parse("S7\nLJ\n").unwrap();
+    assert_eq!(Day10::part_b(&tight).unwrap(), 0);
+}
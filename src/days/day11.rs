@@ -0,0 +1,161 @@
+use anyhow::{bail, Context};
+use chumsky::{prelude::*, text::newline};
+use itertools::Itertools;
+
+use crate::{chumsky_err, chumsky_parse, Grid, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Empty,
+    Galaxy,
+}
+
+fn parser() -> impl Parser<char, Grid<Square>, Error = Simple<char>> {
+    let square = choice((just('.').to(Square::Empty), just('#').to(Square::Galaxy)));
+    let row = square.repeated().then_ignore(newline());
+    row.repeated()
+        .then_ignore(end())
+        .map(Grid::from_rows)
+        .try_map(chumsky_err)
+}
+
+/// What went wrong when a result doesn't fit in a `u128`.
+const OVERFLOW: &str = "the expanded distances overflow a u128";
+
+/// Where each row (or column) ends up once every empty one is `expansion` times as wide, or
+/// `None` if that's past `u128::MAX`.
+fn expanded(occupied: &[bool], expansion: u128) -> Option<Vec<u128>> {
+    let mut next = 0u128;
+    occupied
+        .iter()
+        .map(|&occupied| {
+            let at = next;
+            next = next.checked_add(if occupied { 1 } else { expansion })?;
+            Some(at)
+        })
+        .collect()
+}
+
+/// The galaxies in reading order, as `(row, col)` positions after the expansion.
+fn galaxies(grid: &Grid<Square>, expansion: u128) -> anyhow::Result<Vec<(u128, u128)>> {
+    let is_galaxy = |&sq: &Square| sq == Square::Galaxy;
+    let rows = grid
+        .rows()
+        .map(|row| row.iter().any(is_galaxy))
+        .collect_vec();
+    let cols = grid
+        .columns()
+        .map(|mut col| col.any(is_galaxy))
+        .collect_vec();
+    let rows = expanded(&rows, expansion).context(OVERFLOW)?;
+    let cols = expanded(&cols, expansion).context(OVERFLOW)?;
+
+    Ok(grid
+        .indexed_iter()
+        .filter(|(_, sq)| is_galaxy(sq))
+        .map(|((row, col), _)| (rows[row], cols[col]))
+        .collect())
+}
+
+/// The sum of the distances between every pair of values. Once sorted, the `i`th value is added
+/// for each of the `i` values before it and subtracted for each of the ones after it.
+fn pairwise_sum(mut values: Vec<u128>) -> Option<u128> {
+    values.sort_unstable();
+    let n = values.len() as u128;
+    let (plus, minus) = (0..)
+        .zip(&values)
+        .try_fold((0u128, 0u128), |(plus, minus), (i, &x)| {
+            Some((
+                plus.checked_add(x.checked_mul(i)?)?,
+                minus.checked_add(x.checked_mul(n - 1 - i)?)?,
+            ))
+        })?;
+    Some(plus - minus)
+}
+
+/// The sum of the Manhattan distances between every pair of galaxies.
+fn solve(grid: &Grid<Square>, expansion: u128) -> anyhow::Result<u128> {
+    let galaxies = galaxies(grid, expansion)?;
+    let rows = pairwise_sum(galaxies.iter().map(|&(row, _)| row).collect());
+    let cols = pairwise_sum(galaxies.iter().map(|&(_, col)| col).collect());
+    rows.zip(cols)
+        .and_then(|(rows, cols)| rows.checked_add(cols))
+        .context(OVERFLOW)
+}
+
+/// With an expansion factor, prints the sum of the distances between every pair of galaxies.
+/// Given two galaxy numbers as well, counted from 1 in reading order, prints just their distance.
+pub fn distance(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    const USAGE: &str = "expected an expansion factor, optionally followed by two galaxies";
+
+    let grid = Day11::parse(input)?;
+    let (expansion, pair) = match args {
+        [expansion] => (expansion, None),
+        [expansion, a, b] => (expansion, Some((a, b))),
+        _ => bail!(USAGE),
+    };
+    let expansion = expansion
+        .parse()
+        .with_context(|| format!("invalid expansion factor: {expansion}"))?;
+
+    let Some((a, b)) = pair else {
+        return Ok(solve(&grid, expansion)?.to_string());
+    };
+    let galaxies = galaxies(&grid, expansion)?;
+    let galaxy = |n: &str| -> anyhow::Result<(u128, u128)> {
+        let i = n
+            .parse::<usize>()
+            .with_context(|| format!("invalid galaxy: {n}"))?;
+        i.checked_sub(1)
+            .and_then(|i| galaxies.get(i).copied())
+            .with_context(|| format!("no galaxy {n}"))
+    };
+    let ((a_row, a_col), (b_row, b_col)) = (galaxy(a)?, galaxy(b)?);
+    let distance = a_row
+        .abs_diff(b_row)
+        .checked_add(a_col.abs_diff(b_col))
+        .context(OVERFLOW)?;
+    Ok(distance.to_string())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Grid<Square>;
+
+    fn parse(input: &str) -> anyhow::Result<Grid<Square>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(grid: &Self::Input<'_>) -> anyhow::Result<u128> {
+        solve(grid, 2)
+    }
+
+    fn part_b(grid: &Self::Input<'_>) -> anyhow::Result<u128> {
+        solve(grid, 1_000_000)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day11::parse(include_str!("../../in/11/ex")).unwrap();
+    assert_eq!(solve(&ex, 2).unwrap(), 374);
+    assert_eq!(solve(&ex, 10).unwrap(), 1030);
+    assert_eq!(solve(&ex, 100).unwrap(), 8410);
+    assert_eq!(
+        solve(&ex, 1_000_000_000_000_000_000).unwrap(),
+        82_000_000_000_000_000_210
+    );
+    assert!(solve(&ex, u128::MAX / 2).is_err());
+
+    let galaxies = galaxies(&ex, 2).unwrap();
+    let dist = |a: usize, b: usize| {
+        let ((a_row, a_col), (b_row, b_col)) = (galaxies[a - 1], galaxies[b - 1]);
+        a_row.abs_diff(b_row) + a_col.abs_diff(b_col)
+    };
+    assert_eq!(
+        [dist(5, 9), dist(1, 7), dist(3, 6), dist(8, 9)],
+        [9, 15, 17, 5]
+    );
+}
@@ -0,0 +1,612 @@
+use std::{
+    fmt::{self, Write},
+    fs, iter, mem,
+    ops::{Add, AddAssign},
+};
+
+use anyhow::{bail, ensure, Context};
+use chumsky::prelude::*;
+use itertools::Itertools;
+use num::{bigint::RandBigInt, BigUint};
+use rand::Rng;
+use rayon::prelude::*;
+
+use crate::{chumsky_err, chumsky_parse, Answer, Grid, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl std::fmt::Debug for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Operational => '.',
+                Self::Damaged => '#',
+                Self::Unknown => '?',
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Row {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+fn parser() -> impl Parser<char, Vec<Row>, Error = Simple<char>> {
+    let spring = choice((
+        just('.').to(Spring::Operational),
+        just('#').to(Spring::Damaged),
+        just('?').to(Spring::Unknown),
+    ));
+    let group = text::int(10).from_str::<usize>().try_map(chumsky_err);
+
+    let row = spring
+        .repeated()
+        .at_least(1)
+        .then_ignore(just(' '))
+        .then(group.separated_by(just(',')).at_least(1))
+        .map(|(springs, groups)| Row { springs, groups });
+
+    row.padded().repeated().then_ignore(end())
+}
+
+/// A number of arrangements, which only becomes a big integer once it outgrows a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Count {
+    Small(u64),
+    Big(BigUint),
+}
+
+impl Count {
+    const ZERO: Count = Count::Small(0);
+
+    fn is_zero(&self) -> bool {
+        // A count only becomes big by overflowing.
+        *self == Count::ZERO
+    }
+}
+
+impl Default for Count {
+    fn default() -> Self {
+        Count::ZERO
+    }
+}
+
+impl Add<&Count> for Count {
+    type Output = Count;
+
+    fn add(self, other: &Count) -> Count {
+        match (self, other) {
+            (Count::Small(a), &Count::Small(b)) => match a.checked_add(b) {
+                Some(n) => Count::Small(n),
+                None => Count::Big(BigUint::from(a) + b),
+            },
+            (Count::Small(a), Count::Big(b)) => Count::Big(b + a),
+            (Count::Big(a), &Count::Small(b)) => Count::Big(a + b),
+            (Count::Big(a), Count::Big(b)) => Count::Big(a + b),
+        }
+    }
+}
+
+impl AddAssign<&Count> for Count {
+    fn add_assign(&mut self, other: &Count) {
+        *self = mem::take(self) + other;
+    }
+}
+
+impl From<Count> for BigUint {
+    fn from(count: Count) -> Self {
+        match count {
+            Count::Small(n) => n.into(),
+            Count::Big(n) => n,
+        }
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Count::Small(n) => write!(f, "{n}"),
+            Count::Big(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+impl Answer for Count {
+    fn into_answer(self) -> anyhow::Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+/// The number of ways to fill in the unknown springs so that the damaged ones form `groups`.
+///
+/// Goes through the springs one at a time, keeping the number of ways to have finished `g` groups
+/// with a run of `run` damaged springs at the end in a flat table, one row of runs per `g`. An
+/// extra operational spring at the end closes the last run.
+fn count(springs: &[Spring], groups: &[usize]) -> Count {
+    let width = groups.iter().max().map_or(0, |&len| len) + 1;
+    let at = |g: usize, run: usize| g * width + run;
+
+    let mut ways = vec![Count::ZERO; (groups.len() + 1) * width];
+    let mut next = ways.clone();
+    ways[0] = Count::Small(1);
+
+    for &spring in springs.iter().chain(&[Spring::Operational]) {
+        next.fill(Count::ZERO);
+        for g in 0..=groups.len() {
+            let group = groups.get(g).copied();
+            for run in 0..width {
+                let n = &ways[at(g, run)];
+                if n.is_zero() {
+                    continue;
+                }
+                if spring != Spring::Damaged {
+                    if run == 0 {
+                        next[at(g, 0)] += n;
+                    } else if group == Some(run) {
+                        next[at(g + 1, 0)] += n;
+                    }
+                }
+                if spring != Spring::Operational && group.is_some_and(|len| run < len) {
+                    next[at(g, run + 1)] += n;
+                }
+            }
+        }
+        mem::swap(&mut ways, &mut next);
+    }
+
+    mem::take(&mut ways[at(groups.len(), 0)])
+}
+
+impl Row {
+    /// The row with its springs repeated `factor` times, joined by unknown springs, and its groups
+    /// repeated to match.
+    fn unfold(&self, factor: usize) -> (Vec<Spring>, Vec<usize>) {
+        let springs = vec![self.springs.clone(); factor].join(&Spring::Unknown);
+        (springs, self.groups.repeat(factor))
+    }
+}
+
+/// The total number of arrangements of the rows once unfolded `factor` times.
+fn total(rows: &[Row], factor: usize) -> Count {
+    rows.par_iter()
+        .map(|row| {
+            let (springs, groups) = row.unfold(factor);
+            count(&springs, &groups)
+        })
+        .reduce(Count::default, |a, b| a + &b)
+}
+
+/// Prints the total number of arrangements with the unfold factor given as the argument.
+pub fn unfold(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let [factor] = args else {
+        bail!("expected an unfold factor");
+    };
+    let factor: usize = factor
+        .parse()
+        .with_context(|| format!("invalid unfold factor: {factor}"))?;
+    ensure!(factor > 0, "the unfold factor must be positive");
+
+    Ok(total(&Day12::parse(input)?, factor).to_string())
+}
+
+/// Fills in the unknown springs that are the same in every arrangement, or returns `None` if there
+/// are no arrangements.
+fn settle(springs: &[Spring], groups: &[usize]) -> Option<Vec<Spring>> {
+    if count(springs, groups).is_zero() {
+        return None;
+    }
+
+    let mut springs = springs.to_vec();
+    for i in 0..springs.len() {
+        if springs[i] != Spring::Unknown {
+            continue;
+        }
+        springs[i] = Spring::Damaged;
+        let damaged = !count(&springs, groups).is_zero();
+        springs[i] = Spring::Operational;
+        let operational = !count(&springs, groups).is_zero();
+        springs[i] = match (damaged, operational) {
+            (true, false) => Spring::Damaged,
+            (false, true) => Spring::Operational,
+            _ => Spring::Unknown,
+        };
+    }
+    Some(springs)
+}
+
+/// Counts the arrangements by trying every way to fill in the unknown springs.
+fn brute_force_count(springs: &[Spring], groups: &[usize]) -> u64 {
+    let mut springs = springs.to_vec();
+    let unknowns = springs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &s)| if s == Spring::Unknown { Some(i) } else { None })
+        .collect_vec();
+
+    let mut valid_count = 0;
+
+    for n in 0..1 << unknowns.len() {
+        for (j, &i) in unknowns.iter().enumerate() {
+            if n & (1 << j) == 0 {
+                springs[i] = Spring::Damaged;
+            } else {
+                springs[i] = Spring::Operational;
+            }
+        }
+
+        let mut consecutive = 0;
+        let actual_groups = springs
+            .iter()
+            .copied()
+            .chain(iter::once(Spring::Operational))
+            .filter_map(|s| match s {
+                Spring::Operational => {
+                    let res = consecutive;
+                    consecutive = 0;
+                    if res > 0 {
+                        Some(res)
+                    } else {
+                        None
+                    }
+                }
+                Spring::Damaged => {
+                    consecutive += 1;
+                    None
+                }
+                Spring::Unknown => unreachable!(),
+            });
+
+        if itertools::equal(groups.iter().copied(), actual_groups) {
+            valid_count += 1;
+        }
+    }
+
+    valid_count
+}
+
+/// The arrangements of a row in lexicographic order, with `#` before `.`. They're found lazily,
+/// only ever following choices that the counts say leave at least one arrangement.
+pub struct Arrangements<'a> {
+    groups: &'a [usize],
+    stack: Vec<Vec<Spring>>,
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = Vec<Spring>;
+
+    fn next(&mut self) -> Option<Vec<Spring>> {
+        while let Some(springs) = self.stack.pop() {
+            let Some(i) = springs.iter().position(|&s| s == Spring::Unknown) else {
+                return Some(springs);
+            };
+            for choice in [Spring::Operational, Spring::Damaged] {
+                let mut next = springs.clone();
+                next[i] = choice;
+                if !count(&next, self.groups).is_zero() {
+                    self.stack.push(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn arrangements<'a>(springs: &[Spring], groups: &'a [usize]) -> Arrangements<'a> {
+    let mut stack = Vec::new();
+    if !count(springs, groups).is_zero() {
+        stack.push(springs.to_vec());
+    }
+    Arrangements { groups, stack }
+}
+
+/// The `k`th of the [`Arrangements`], counted from 0, found without listing the ones before it.
+fn nth_arrangement(springs: &[Spring], groups: &[usize], mut k: BigUint) -> Option<Vec<Spring>> {
+    if k >= count(springs, groups).into() {
+        return None;
+    }
+
+    let mut springs = springs.to_vec();
+    for i in 0..springs.len() {
+        if springs[i] != Spring::Unknown {
+            continue;
+        }
+        springs[i] = Spring::Damaged;
+        let damaged = count(&springs, groups).into();
+        if k >= damaged {
+            k -= damaged;
+            springs[i] = Spring::Operational;
+        }
+    }
+    Some(springs)
+}
+
+/// An arrangement picked uniformly at random, or `None` if there are none.
+fn sample(springs: &[Spring], groups: &[usize], rng: &mut impl Rng) -> Option<Vec<Spring>> {
+    let total = count(springs, groups);
+    if total.is_zero() {
+        return None;
+    }
+    nth_arrangement(springs, groups, rng.gen_biguint_below(&total.into()))
+}
+
+fn show(springs: &[Spring]) -> String {
+    springs.iter().map(|s| format!("{s:?}")).collect()
+}
+
+/// Describes the row with the number given as the first argument, counted from 1: how many
+/// arrangements it has by both parts' methods, and which springs are the same in all of them.
+/// A second argument of `all`, `random` or a number `k` also prints those arrangements.
+pub fn row(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    /// Rows with more unknowns than this are too slow to brute force.
+    const MAX_BRUTE_FORCE: usize = 24;
+
+    let rows = Day12::parse(input)?;
+    let (n, which) = match args {
+        [n] => (n, None),
+        [n, which] => (n, Some(*which)),
+        _ => bail!("expected a row number, optionally followed by `all`, `random` or a number"),
+    };
+    let n: usize = n.parse().with_context(|| format!("invalid row: {n}"))?;
+    let Row { springs, groups } = n
+        .checked_sub(1)
+        .and_then(|i| rows.get(i))
+        .with_context(|| format!("no row {n}"))?;
+
+    let mut out = String::new();
+    writeln!(out, "row: {} {}", show(springs), groups.iter().join(","))?;
+    write!(out, "arrangements: {}", count(springs, groups))?;
+    if springs.iter().filter(|&&s| s == Spring::Unknown).count() <= MAX_BRUTE_FORCE {
+        write!(
+            out,
+            " (brute force: {})",
+            brute_force_count(springs, groups)
+        )?;
+    }
+    writeln!(out)?;
+    if let Some(forced) = settle(springs, groups) {
+        writeln!(out, "forced: {}", show(&forced))?;
+    }
+
+    match which {
+        None => {}
+        Some("all") => {
+            for arrangement in arrangements(springs, groups) {
+                writeln!(out, "{}", show(&arrangement))?;
+            }
+        }
+        Some("random") => {
+            if let Some(arrangement) = sample(springs, groups, &mut rand::thread_rng()) {
+                writeln!(out, "{}", show(&arrangement))?;
+            }
+        }
+        Some(k) => {
+            let k: BigUint = k
+                .parse()
+                .with_context(|| format!("invalid arrangement: {k}"))?;
+            let arrangement = nth_arrangement(springs, groups, k.clone())
+                .with_context(|| format!("no arrangement {k}"))?;
+            writeln!(out, "{}", show(&arrangement))?;
+        }
+    }
+    Ok(out)
+}
+
+/// A nonogram: a picture of damaged springs, given the groups in every row and column.
+#[derive(Debug, Clone)]
+pub struct Nonogram {
+    rows: Vec<Vec<usize>>,
+    cols: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions {
+    None,
+    One(Grid<Spring>),
+    /// Two of the solutions.
+    Many(Grid<Spring>, Grid<Spring>),
+}
+
+impl Nonogram {
+    /// Reads the groups of every row, a blank line, then the groups of every column. Each line
+    /// lists its groups separated by commas, with `0` for a line without any.
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        let (rows, cols) = input
+            .trim()
+            .split_once("\n\n")
+            .context("expected a blank line between the rows and the columns")?;
+
+        let clues = |block: &str| -> anyhow::Result<Vec<Vec<usize>>> {
+            block
+                .lines()
+                .map(|line| {
+                    line.split(',')
+                        .map(|n| n.trim().parse())
+                        .filter_ok(|&n| n > 0)
+                        .collect::<Result<_, _>>()
+                        .with_context(|| format!("invalid clue: {line}"))
+                })
+                .collect()
+        };
+
+        Ok(Nonogram {
+            rows: clues(rows)?,
+            cols: clues(cols)?,
+        })
+    }
+
+    /// Settles every row and column in turn until nothing changes, or returns `None` on a
+    /// contradiction.
+    fn propagate(&self, mut grid: Grid<Spring>) -> Option<Grid<Spring>> {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (r, groups) in self.rows.iter().enumerate() {
+                let settled = settle(grid.row(r), groups)?;
+                if settled != grid.row(r) {
+                    changed = true;
+                    for (c, spring) in settled.into_iter().enumerate() {
+                        grid[(r, c)] = spring;
+                    }
+                }
+            }
+            for (c, groups) in self.cols.iter().enumerate() {
+                let col = grid.column(c).copied().collect_vec();
+                let settled = settle(&col, groups)?;
+                if settled != col {
+                    changed = true;
+                    for (r, spring) in settled.into_iter().enumerate() {
+                        grid[(r, c)] = spring;
+                    }
+                }
+            }
+        }
+        Some(grid)
+    }
+
+    /// Adds up to `limit` solutions consistent with `grid` to `found`, guessing a cell whenever
+    /// propagation gets stuck.
+    fn search(&self, grid: Grid<Spring>, limit: usize, found: &mut Vec<Grid<Spring>>) {
+        let Some(grid) = self.propagate(grid) else {
+            return;
+        };
+        let Some(((r, c), _)) = grid.indexed_iter().find(|(_, &s)| s == Spring::Unknown) else {
+            found.push(grid);
+            return;
+        };
+
+        for guess in [Spring::Damaged, Spring::Operational] {
+            if found.len() == limit {
+                return;
+            }
+            let mut grid = grid.clone();
+            grid[(r, c)] = guess;
+            self.search(grid, limit, found);
+        }
+    }
+
+    pub fn solve(&self) -> Solutions {
+        let grid = Grid::new(self.rows.len(), self.cols.len(), Spring::Unknown);
+        let mut found = Vec::new();
+        self.search(grid, 2, &mut found);
+
+        let mut found = found.into_iter();
+        match (found.next(), found.next()) {
+            (None, _) => Solutions::None,
+            (Some(a), None) => Solutions::One(a),
+            (Some(a), Some(b)) => Solutions::Many(a, b),
+        }
+    }
+}
+
+fn picture(grid: &Grid<Spring>) -> String {
+    grid.rows().map(show).join("\n")
+}
+
+/// Solves the nonogram in the clue file given as the argument, in the format read by
+/// [`Nonogram::parse`].
+pub fn nonogram(_input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let [path] = args else {
+        bail!("expected a clue file, such as misc/12/heart");
+    };
+    let clues = fs::read_to_string(path).with_context(|| format!("couldn't read {path}"))?;
+    Ok(match Nonogram::parse(&clues)?.solve() {
+        Solutions::None => "no solutions".to_owned(),
+        Solutions::One(grid) => format!("one solution:\n{}", picture(&grid)),
+        Solutions::Many(a, b) => format!(
+            "many solutions, such as:\n{}\n\n{}",
+            picture(&a),
+            picture(&b)
+        ),
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Row>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Row>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(rows: &Self::Input<'_>) -> Count {
+        total(rows, 1)
+    }
+
+    fn part_b(rows: &Self::Input<'_>) -> Count {
+        total(rows, 5)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use rand::SeedableRng;
+
+    use crate::ParseError;
+
+    let heart = Nonogram::parse(include_str!("../../misc/12/heart")).unwrap();
+    let Solutions::One(grid) = heart.solve() else {
+        panic!("expected one solution");
+    };
+    assert_eq!(picture(&grid), ".#.#.\n#####\n#####\n.###.\n..#..");
+
+    let diagonal = Nonogram::parse("1\n1\n\n1\n1").unwrap();
+    assert!(matches!(diagonal.solve(), Solutions::Many(..)));
+    let impossible = Nonogram::parse("2\n0\n\n1\n0").unwrap();
+    assert_eq!(impossible.solve(), Solutions::None);
+
+    let ex = Day12::parse(include_str!("../../in/12/ex")).unwrap();
+    let err = Day12::parse("???.### 1,1,3\n.??..??...?##. 1,x\n").unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column), (2, 18));
+    let Row { springs, groups } = &ex[5];
+    let all = arrangements(springs, groups).collect_vec();
+    assert_eq!(all.len(), 10);
+    assert!(all
+        .iter()
+        .map(|a| show(a))
+        .tuple_windows()
+        .all(|(a, b)| a < b));
+    for (k, arrangement) in all.iter().enumerate() {
+        assert_eq!(
+            nth_arrangement(springs, groups, k.into()).as_ref(),
+            Some(arrangement)
+        );
+        assert_eq!(count(arrangement, groups), Count::Small(1));
+    }
+    assert_eq!(nth_arrangement(springs, groups, 10u32.into()), None);
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(12);
+    for _ in 0..20 {
+        assert!(all.contains(&sample(springs, groups, &mut rng).unwrap()));
+    }
+
+    let Row { springs, groups } = &ex[0];
+    assert_eq!(show(&settle(springs, groups).unwrap()), "#.#.###");
+
+    assert_eq!(total(&ex, 1), Count::Small(21));
+    assert_eq!(total(&ex, 5), Count::Small(525152));
+    let brute = ex
+        .iter()
+        .map(|Row { springs, groups }| brute_force_count(springs, groups))
+        .sum::<u64>();
+    assert_eq!(brute, 21);
+
+    let huge = Count::Small(u64::MAX) + &Count::Small(1);
+    assert_eq!(huge.to_string(), "18446744073709551616");
+    // Choosing 40 springs out of 120, once the gaps between them are taken out.
+    let many = Day12::parse("??? 1\n").unwrap();
+    let count = total(&many, 40);
+    assert!(matches!(count, Count::Big(_)));
+    assert_eq!(count.to_string(), "114556848244965165743109806892471");
+}
@@ -0,0 +1,110 @@
+use std::{fmt::Debug, iter::zip};
+
+use chumsky::{prelude::*, text::newline};
+use itertools::Itertools;
+
+use crate::{chumsky_err, chumsky_parse, Grid, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Ash,
+    Rock,
+}
+
+impl Square {
+    fn swap(&mut self) {
+        *self = match self {
+            Self::Ash => Self::Rock,
+            Self::Rock => Self::Ash,
+        }
+    }
+}
+
+fn parser() -> impl Parser<char, Vec<Grid<Square>>, Error = Simple<char>> {
+    let square = choice((just('.').to(Square::Ash), just('#').to(Square::Rock)));
+    let row = square.repeated().at_least(1).then_ignore(newline());
+    let grid = row
+        .repeated()
+        .at_least(1)
+        .then_ignore(newline().or_not())
+        .map(Grid::from_rows)
+        .try_map(chumsky_err);
+    grid.repeated().at_least(1).then_ignore(end())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reflection {
+    MirrorX(usize),
+    MirrorY(usize),
+}
+
+fn reflections(grid: &Grid<Square>) -> impl Iterator<Item = Reflection> + Debug + '_ {
+    let (h, w) = grid.shape();
+
+    let xx = (1..w)
+        .filter(move |&x_piv| {
+            zip((0..x_piv).rev(), x_piv..w).all(|(x1, x2)| grid.column(x1).eq(grid.column(x2)))
+        })
+        .map(Reflection::MirrorX);
+
+    let yy = (1..h)
+        .filter(move |&y_piv| {
+            zip((0..y_piv).rev(), y_piv..h).all(|(y1, y2)| grid.row(y1) == grid.row(y2))
+        })
+        .map(Reflection::MirrorY);
+
+    itertools::chain(xx, yy)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Grid<Square>>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Grid<Square>>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(grids: &Self::Input<'_>) -> usize {
+        let mut sum = 0;
+        for grid in grids.iter() {
+            sum += match reflections(grid).exactly_one() {
+                Ok(Reflection::MirrorX(x)) => x,
+                Ok(Reflection::MirrorY(y)) => 100 * y,
+                Err(_) => unreachable!(),
+            }
+        }
+
+        sum
+    }
+
+    fn part_b(grids: &Self::Input<'_>) -> usize {
+        let mut sum = 0;
+        for mut grid in grids.iter().cloned() {
+            let (h, w) = grid.shape();
+            let original = reflections(&grid).exactly_one().unwrap();
+
+            let new = (0..h)
+                .cartesian_product(0..w)
+                .filter_map(|(y, x)| {
+                    grid[(y, x)].swap();
+                    let res = reflections(&grid)
+                        .filter(|&r| r != original)
+                        .exactly_one()
+                        .ok();
+                    grid[(y, x)].swap();
+                    res
+                })
+                .dedup()
+                .exactly_one()
+                .unwrap();
+
+            sum += match new {
+                Reflection::MirrorX(x) => x,
+                Reflection::MirrorY(y) => 100 * y,
+            };
+        }
+
+        sum
+    }
+}
@@ -0,0 +1,98 @@
+use chumsky::{prelude::*, text::newline};
+use derive_more::Display;
+
+use crate::{chumsky_err, chumsky_parse, cycle, Grid, GridViewMut, Solution};
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    #[display(fmt = ".")]
+    Empty,
+    #[display(fmt = "#")]
+    Square,
+    #[display(fmt = "O")]
+    Round,
+}
+
+fn parser() -> impl Parser<char, Grid<Tile>, Error = Simple<char>> {
+    let tile = choice((
+        just('.').to(Tile::Empty),
+        just('#').to(Tile::Square),
+        just('O').to(Tile::Round),
+    ));
+    let row = tile.repeated().at_least(1).then_ignore(newline());
+    row.repeated()
+        .at_least(1)
+        .map(Grid::from_rows)
+        .try_map(chumsky_err)
+        .padded()
+        .then_ignore(end())
+}
+
+fn tilt_north(grid: &mut GridViewMut<Tile>) {
+    let (h, w) = grid.shape();
+    for col in 0..w {
+        let mut tail = h;
+        for head in (0..h).rev() {
+            match grid[(head, col)] {
+                Tile::Empty => {
+                    tail -= 1;
+                    grid[(head, col)] = Tile::Round;
+                    grid[(tail, col)] = Tile::Empty;
+                }
+                Tile::Square => tail = head,
+                Tile::Round => (),
+            }
+        }
+    }
+}
+
+fn calc_load(grid: &Grid<Tile>) -> u64 {
+    let mut load = 0;
+    for col in grid.columns() {
+        for (i, &tile) in col.enumerate() {
+            if tile == Tile::Round {
+                load += (grid.nrows() - i) as u64
+            }
+        }
+    }
+    load
+}
+
+fn run_cycle(grid: &mut Grid<Tile>) {
+    let mut view = grid.view_mut();
+    for _ in 0..4 {
+        tilt_north(&mut view);
+        view = view.rot90();
+    }
+}
+
+const ITERS: usize = 1_000_000_000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> anyhow::Result<Grid<Tile>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(grid: &Self::Input<'_>) -> u64 {
+        let mut grid = grid.clone();
+        tilt_north(&mut grid.view_mut());
+        calc_load(&grid)
+    }
+
+    fn part_b(grid: &Self::Input<'_>) -> u64 {
+        let grid = cycle::state_at(
+            grid.clone(),
+            |grid| {
+                let mut grid = grid.clone();
+                run_cycle(&mut grid);
+                grid
+            },
+            ITERS,
+        );
+        calc_load(&grid)
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use rustc_hash::FxHashSet;
+use strum::FromRepr;
+use winnow::{token::any, PResult, Parser};
+
+use crate::{grid, winnow_parse, Dir, Grid, Pos, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromRepr)]
+#[repr(u8)]
+pub enum Tile {
+    Empty = b'.',
+    Vertical = b'|',
+    Horizontal = b'-',
+    Diag1 = b'/',
+    Diag2 = b'\\',
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8 as char)
+    }
+}
+
+fn tile(input: &mut &str) -> PResult<Tile> {
+    any.verify_map(|c| u8::try_from(c).ok().and_then(Tile::from_repr))
+        .parse_next(input)
+}
+
+fn shoot_beam(grid: &Grid<Tile>, start_pos: Pos, start_dir: Dir) -> Grid<bool> {
+    let mut beams = vec![(start_pos, start_dir)];
+    let mut next = Vec::new();
+    let mut seen = FxHashSet::default();
+    let mut energized = Grid::new(grid.nrows(), grid.ncols(), false);
+
+    while !beams.is_empty() {
+        for (pos, dir) in beams.drain(..) {
+            if !seen.insert((pos, dir)) {
+                continue;
+            }
+
+            energized[pos] = true;
+
+            let mut push = |dir| {
+                if let Some(pos) = pos.step(dir, grid.shape()) {
+                    next.push((pos, dir));
+                }
+            };
+
+            match grid[pos] {
+                Tile::Diag1 if dir.is_horizontal() => push(dir.turn_left()),
+                Tile::Diag1 => push(dir.turn_right()),
+                Tile::Diag2 if dir.is_horizontal() => push(dir.turn_right()),
+                Tile::Diag2 => push(dir.turn_left()),
+                Tile::Vertical if dir.is_horizontal() => {
+                    push(Dir::Down);
+                    push(Dir::Up);
+                }
+                Tile::Horizontal if !dir.is_horizontal() => {
+                    push(Dir::Right);
+                    push(Dir::Left);
+                }
+                _ => push(dir),
+            }
+        }
+
+        std::mem::swap(&mut beams, &mut next);
+    }
+
+    energized
+}
+
+fn count_energized(grid: &Grid<Tile>, start_pos: Pos, start_dir: Dir) -> usize {
+    shoot_beam(grid, start_pos, start_dir)
+        .iter()
+        .filter(|&&b| b)
+        .count()
+}
+
+fn starting_states(grid: &Grid<Tile>) -> impl Iterator<Item = (Pos, Dir)> {
+    let (m, n) = grid.shape();
+    itertools::chain!(
+        (0..m).map(move |y| (Pos::new(y, 0), Dir::Right)),
+        (0..m).map(move |y| (Pos::new(y, n - 1), Dir::Left)),
+        (0..n).map(move |x| (Pos::new(0, x), Dir::Down)),
+        (0..n).map(move |x| (Pos::new(m - 1, x), Dir::Up)),
+    )
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> anyhow::Result<Grid<Tile>> {
+        Ok(winnow_parse(grid(tile), input)?)
+    }
+
+    fn part_a(grid: &Self::Input<'_>) -> usize {
+        let energized = shoot_beam(grid, Pos::new(0, 0), Dir::Right);
+        energized.iter().filter(|&&b| b).count()
+    }
+
+    fn part_b(grid: &Self::Input<'_>) -> usize {
+        starting_states(grid)
+            .map(|(pos, dir)| count_energized(grid, pos, dir))
+            .max()
+            .unwrap()
+    }
+}
@@ -0,0 +1,87 @@
+use std::ops::RangeInclusive;
+
+use winnow::{prelude::*, token::any};
+
+use crate::{grid, search, winnow_parse, Dir, Grid, Pos, Solution};
+
+fn tile(input: &mut &str) -> PResult<u8> {
+    any.verify_map(|c: char| {
+        if c.is_ascii_digit() {
+            Some(c as u8 - b'0')
+        } else {
+            None
+        }
+    })
+    .parse_next(input)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Node {
+    pos: Pos,
+    chain: Option<(Dir, u8)>,
+}
+
+/// The states reachable in one move from `node`, with the heat lost entering them.
+fn successors<'a>(
+    node: &Node,
+    grid: &'a Grid<u8>,
+    chain: &'a RangeInclusive<u8>,
+) -> impl Iterator<Item = (Node, u32)> + 'a {
+    let node = *node;
+    Dir::ALL.into_iter().filter_map(move |dir| {
+        let n_pos = node.pos.step(dir, grid.shape())?;
+        let n_chain = if let Some((last_dir, last_consec)) = node.chain {
+            let can_turn = chain.contains(&last_consec);
+            let must_turn = last_consec == *chain.end();
+            assert!(last_consec <= *chain.end());
+
+            if last_dir == dir && !must_turn {
+                (dir, last_consec + 1)
+            } else if last_dir != dir && last_dir != dir.reverse() && can_turn {
+                (dir, 1)
+            } else {
+                return None;
+            }
+        } else {
+            (dir, 1)
+        };
+        let n_node = Node {
+            pos: n_pos,
+            chain: Some(n_chain),
+        };
+        Some((n_node, grid[n_pos] as u32))
+    })
+}
+
+fn min_heat_loss(grid: &Grid<u8>, chain: RangeInclusive<u8>) -> u32 {
+    let start = Node {
+        pos: Pos::new(0, 0),
+        chain: None,
+    };
+    let end = Pos::new(grid.nrows() - 1, grid.ncols() - 1);
+    search::dijkstra(
+        start,
+        |node| successors(node, grid, &chain),
+        |node| node.pos == end,
+    )
+    .unwrap()
+    .cost
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> anyhow::Result<Grid<u8>> {
+        Ok(winnow_parse(grid(tile), input)?)
+    }
+
+    fn part_a(grid: &Self::Input<'_>) -> u32 {
+        min_heat_loss(grid, 1..=3)
+    }
+
+    fn part_b(grid: &Self::Input<'_>) -> u32 {
+        min_heat_loss(grid, 4..=10)
+    }
+}
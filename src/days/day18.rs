@@ -3,7 +3,6 @@ use std::{
     iter,
 };
 
-use aoc23::read_stdin_to_string;
 use derive_more::Display;
 use itertools::Itertools;
 use nalgebra::Vector2;
@@ -15,28 +14,17 @@ use winnow::{
     token::{any, take_while},
 };
 
-#[derive(Debug, Display, Clone, Copy)]
-enum Dir {
-    Right,
-    Left,
-    Down,
-    Up,
-}
+use crate::{winnow_parse, Dir, Solution};
 
-impl Dir {
-    fn to_vec(self) -> Vector2<i64> {
-        match self {
-            Dir::Right => Vector2::new(1, 0),
-            Dir::Left => Vector2::new(-1, 0),
-            Dir::Down => Vector2::new(0, 1),
-            Dir::Up => Vector2::new(0, -1),
-        }
-    }
+/// `dir` as an `(x, y)` vector, with y growing downwards.
+fn to_vec(dir: Dir) -> Vector2<i64> {
+    let delta = dir.delta();
+    Vector2::new(delta.col as i64, delta.row as i64)
 }
 
 #[derive(Debug, Display, Clone)]
 #[display(fmt = "{dir} {len} (#{color})")]
-struct Instruction {
+pub struct Instruction {
     dir: Dir,
     len: u64,
     color: String,
@@ -88,7 +76,7 @@ fn solve(lines: &[(Dir, u64)]) -> u64 {
         .iter()
         .circular_tuple_windows()
         .scan(Vector2::zeros(), |pos, (&(dir1, len), &(dir2, _))| {
-            *pos += dir1.to_vec() * (len as i64);
+            *pos += to_vec(dir1) * (len as i64);
 
             let corner = match (dir1, dir2) {
                 (Dir::Up, Dir::Right) | (Dir::Left, Dir::Down) => Corner::TopLeft,
@@ -179,35 +167,42 @@ fn solve(lines: &[(Dir, u64)]) -> u64 {
         .sum::<u64>()
 }
 
-fn main() {
-    let input = read_stdin_to_string();
-    let lines: Vec<_> = repeat(.., line).parse(input.as_str()).unwrap();
+pub struct Day18;
 
-    let a_lines = lines
-        .iter()
-        .map(|&Instruction { dir, len, .. }| (dir, len))
-        .collect_vec();
+impl Solution for Day18 {
+    type Input<'a> = Vec<Instruction>;
 
-    let b_lines = {
-        lines.iter().map(|Instruction { color, .. }| {
-            let (a, b) = color.split_at(5);
-            (
-                match b {
-                    "0" => Dir::Right,
-                    "1" => Dir::Down,
-                    "2" => Dir::Left,
-                    "3" => Dir::Up,
-                    _ => unreachable!(),
-                },
-                u64::from_str_radix(a, 16).unwrap(),
-            )
-        })
+    fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+        Ok(winnow_parse(repeat(.., line), input)?)
     }
-    .collect_vec();
 
-    let a = solve(&a_lines);
-    println!("a: {a}");
+    fn part_a(lines: &Self::Input<'_>) -> u64 {
+        let a_lines = lines
+            .iter()
+            .map(|&Instruction { dir, len, .. }| (dir, len))
+            .collect_vec();
 
-    let b = solve(&b_lines);
-    println!("b: {b}");
+        solve(&a_lines)
+    }
+
+    fn part_b(lines: &Self::Input<'_>) -> u64 {
+        let b_lines = {
+            lines.iter().map(|Instruction { color, .. }| {
+                let (a, b) = color.split_at(5);
+                (
+                    match b {
+                        "0" => Dir::Right,
+                        "1" => Dir::Down,
+                        "2" => Dir::Left,
+                        "3" => Dir::Up,
+                        _ => unreachable!(),
+                    },
+                    u64::from_str_radix(a, 16).unwrap(),
+                )
+            })
+        }
+        .collect_vec();
+
+        solve(&b_lines)
+    }
 }
@@ -1,9 +1,5 @@
-use std::ops::Range;
-
-use aoc23::read_stdin_to_string;
 use enum_map::{enum_map, Enum, EnumMap};
 use itertools::Itertools;
-use rangetools::Rangetools;
 use rustc_hash::FxHashMap;
 use winnow::{
     ascii::{dec_uint, multispace0},
@@ -13,8 +9,10 @@ use winnow::{
     trace::trace,
 };
 
+use crate::{winnow_parse, IntervalSet, Solution};
+
 #[derive(Debug, Clone, Copy, Enum)]
-enum Category {
+pub enum Category {
     X,
     M,
     A,
@@ -42,7 +40,7 @@ struct Rule {
 }
 
 #[derive(Debug, Clone)]
-struct Workflow {
+pub struct Workflow {
     rules: Vec<Rule>,
     default: Action,
 }
@@ -53,15 +51,15 @@ fn combinations(
     workflows: &FxHashMap<String, Workflow>,
     tag: &str,
     index: usize,
-    ranges: EnumMap<Category, Range<u16>>,
+    ranges: EnumMap<Category, IntervalSet<u16>>,
 ) -> (u64, u64) {
-    assert!(!ranges.values().any(Range::is_empty));
+    assert!(!ranges.values().any(IntervalSet::is_empty));
 
     let workflow = &workflows[tag];
 
-    let count = |rs: &EnumMap<_, Range<_>>| rs.values().map(|r| r.len() as u64).product();
+    let count = |rs: &EnumMap<_, IntervalSet<u16>>| rs.values().map(|r| r.size() as u64).product();
 
-    let combs_with_action = |a: &Action, rs: EnumMap<_, Range<_>>| match a {
+    let combs_with_action = |a: &Action, rs: EnumMap<_, IntervalSet<_>>| match a {
         Action::Accept => {
             let count = count(&rs);
             (count, 0)
@@ -76,14 +74,15 @@ fn combinations(
     match workflow.rules.get(index) {
         None => combs_with_action(&workflow.default, ranges),
         Some(rule) => {
-            let range = ranges[rule.category].clone();
+            let range = &ranges[rule.category];
             let [pass, fail] = match rule.condition {
-                Condition::GreaterThan(n) => [
-                    range.clone().intersection(n + 1..),
-                    range.intersection(..n + 1),
-                ],
+                Condition::GreaterThan(n) => {
+                    let (fail, pass) = range.split_at(n + 1);
+                    [pass, fail]
+                }
                 Condition::LessThan(n) => {
-                    [range.clone().intersection(..n), range.intersection(n..)]
+                    let (pass, fail) = range.split_at(n);
+                    [pass, fail]
                 }
             }
             .map(|r| {
@@ -92,7 +91,7 @@ fn combinations(
                 } else {
                     Some({
                         let mut rs = ranges.clone();
-                        rs[rule.category] = r.into();
+                        rs[rule.category] = r;
                         rs
                     })
                 }
@@ -115,7 +114,7 @@ fn solve_a(workflows: &FxHashMap<String, Workflow>, parts: &[Part]) -> u64 {
                 workflows,
                 "in",
                 0,
-                EnumMap::from_fn(|c| part[c]..part[c] + 1),
+                EnumMap::from_fn(|c| IntervalSet::from(part[c]..part[c] + 1)),
             ) {
                 (0, _) => 0,
                 (1, _) => part.values().copied().map_into::<u64>().sum(),
@@ -126,24 +125,35 @@ fn solve_a(workflows: &FxHashMap<String, Workflow>, parts: &[Part]) -> u64 {
 }
 
 fn solve_b(workflows: &FxHashMap<String, Workflow>) -> (u64, u64) {
-    combinations(workflows, "in", 0, EnumMap::from_fn(|_| 1..4001))
+    combinations(
+        workflows,
+        "in",
+        0,
+        EnumMap::from_fn(|_| IntervalSet::from(1..4001)),
+    )
 }
 
-fn solve(input: &str) -> (u64, u64) {
-    let (workflows, parts) = input_parser.parse(input).unwrap();
-    let workflows = workflows.into_iter().collect::<FxHashMap<_, _>>();
+type Input = (FxHashMap<String, Workflow>, Vec<Part>);
 
-    let a = solve_a(&workflows, &parts);
-    let (b, b2) = solve_b(&workflows);
-    assert_eq!(b + b2, 4000u64.pow(4));
+pub struct Day19;
 
-    (a, b)
-}
+impl Solution for Day19 {
+    type Input<'a> = Input;
 
-fn main() {
-    let input = read_stdin_to_string();
-    let (a, b) = solve(input.as_str());
-    println!("a: {a}\nb: {b}");
+    fn parse(input: &str) -> anyhow::Result<Input> {
+        let (workflows, parts) = winnow_parse(input_parser, input)?;
+        Ok((workflows.into_iter().collect(), parts))
+    }
+
+    fn part_a((workflows, parts): &Self::Input<'_>) -> u64 {
+        solve_a(workflows, parts)
+    }
+
+    fn part_b((workflows, _): &Self::Input<'_>) -> u64 {
+        let (b, b2) = solve_b(workflows);
+        assert_eq!(b + b2, 4000u64.pow(4));
+        b
+    }
 }
 
 fn category(input: &mut &str) -> PResult<Category> {
@@ -262,12 +272,11 @@ fn input_parser(input: &mut &str) -> PResult<(Vec<(String, Workflow)>, Vec<Part>
 #[cfg(test)]
 #[test]
 fn test() {
-    assert_eq!(
-        solve(include_str!("../../in/19/ex")),
-        (19114, 167409079868000)
-    );
-    assert_eq!(
-        solve(include_str!("../../in/19/i")),
-        (446517, 130090458884662)
-    );
+    let ex = Day19::parse(include_str!("../../in/19/ex")).unwrap();
+    let i = Day19::parse(include_str!("../../in/19/i")).unwrap();
+
+    assert_eq!(Day19::part_a(&ex), 19114);
+    assert_eq!(Day19::part_b(&ex), 167409079868000);
+    assert_eq!(Day19::part_a(&i), 446517);
+    assert_eq!(Day19::part_b(&i), 130090458884662);
 }
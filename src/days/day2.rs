@@ -0,0 +1,211 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, str::FromStr};
+
+use anyhow::{bail, Context};
+use chumsky::prelude::*;
+use itertools::Itertools;
+use num::BigUint;
+
+use crate::{chumsky_err, chumsky_parse, ParseError, Solution};
+
+/// A number of cubes of each colour. Colours that don't appear count as zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes(BTreeMap<String, u32>);
+
+impl Cubes {
+    /// Whether every colour is at least as plentiful here as in `other`.
+    pub fn covers(&self, other: &Cubes) -> bool {
+        other
+            .0
+            .iter()
+            .all(|(colour, &n)| self.0.get(colour).copied().unwrap_or(0) >= n)
+    }
+
+    /// The most cubes of each colour found in either.
+    pub fn max(mut self, other: &Cubes) -> Cubes {
+        for (colour, &n) in &other.0 {
+            let m = self.0.entry(colour.clone()).or_default();
+            *m = (*m).max(n);
+        }
+        self
+    }
+
+    /// The product of the red, green and blue counts, so zero if any of them is missing.
+    pub fn power(&self) -> BigUint {
+        ["red", "green", "blue"]
+            .iter()
+            .map(|&colour| BigUint::from(self.0.get(colour).copied().unwrap_or(0)))
+            .product()
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Cubes {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        let mut cubes = BTreeMap::new();
+        for (colour, n) in iter {
+            *cubes.entry(colour.into()).or_default() += n;
+        }
+        Cubes(cubes)
+    }
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        chumsky_parse(cubes().padded().then_ignore(end()), s)
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = self.0.iter().map(|(colour, n)| format!("{n} {colour}"));
+        write!(f, "{}", cubes.format(", "))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: u32,
+    draws: Vec<Cubes>,
+}
+
+impl Game {
+    /// The fewest cubes the bag could have held for this game.
+    pub fn min_bag(&self) -> Cubes {
+        self.draws.iter().fold(Cubes::default(), Cubes::max)
+    }
+
+    pub fn possible_with(&self, bag: &Cubes) -> bool {
+        self.draws.iter().all(|draw| bag.covers(draw))
+    }
+}
+
+fn cubes() -> impl Parser<char, Cubes, Error = Simple<char>> + Clone {
+    let count = text::int(10)
+        .from_str::<u32>()
+        .try_map(chumsky_err)
+        .padded();
+    let colour = text::ident().padded();
+
+    count
+        .then(colour)
+        .separated_by(just(','))
+        .at_least(1)
+        .map(|cubes| cubes.into_iter().map(|(n, colour)| (colour, n)).collect())
+}
+
+fn parser() -> impl Parser<char, Vec<Game>, Error = Simple<char>> {
+    let id = text::int(10)
+        .from_str::<u32>()
+        .try_map(chumsky_err)
+        .padded();
+
+    let game = just("Game")
+        .ignore_then(id)
+        .then_ignore(just(':'))
+        .then(cubes().separated_by(just(';')))
+        .map(|(id, draws)| Game { id, draws });
+
+    game.padded().repeated().then_ignore(end())
+}
+
+/// Reads a bag given either directly or as the path of a file containing it.
+fn bag_arg(arg: &str) -> anyhow::Result<Cubes> {
+    let text = if Path::new(arg).is_file() {
+        fs::read_to_string(arg).with_context(|| format!("couldn't read {arg}"))?
+    } else {
+        arg.to_owned()
+    };
+    Ok(text.parse()?)
+}
+
+/// The games picked by an optional game id argument.
+fn selected_games<'a>(games: &'a [Game], args: &[&str]) -> anyhow::Result<Vec<&'a Game>> {
+    match args {
+        [] => Ok(games.iter().collect()),
+        [id] => {
+            let id: u32 = id.parse().with_context(|| format!("invalid game: {id}"))?;
+            let game = games.iter().find(|game| game.id == id);
+            Ok(vec![game.with_context(|| format!("no game {id}"))?])
+        }
+        _ => bail!("expected at most one game"),
+    }
+}
+
+/// Prints the ids of the games that are possible with the bag given as the argument.
+pub fn possible(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let games = Day2::parse(input)?;
+    let [bag] = args else {
+        bail!("expected a bag, such as \"12 red, 13 green, 14 blue\"");
+    };
+    let bag = bag_arg(bag)?;
+
+    Ok(games
+        .iter()
+        .filter(|game| game.possible_with(&bag))
+        .map(|game| game.id)
+        .join("\n"))
+}
+
+/// Prints the smallest possible bag for each game, or for the game given as the argument.
+pub fn min_bag(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let games = Day2::parse(input)?;
+    Ok(selected_games(&games, args)?
+        .into_iter()
+        .map(|game| format!("Game {}: {}", game.id, game.min_bag()))
+        .join("\n"))
+}
+
+/// Prints the power of the smallest bag for each game, or for the game given as the argument.
+pub fn power(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let games = Day2::parse(input)?;
+    Ok(selected_games(&games, args)?
+        .into_iter()
+        .map(|game| format!("Game {}: {}", game.id, game.min_bag().power()))
+        .join("\n"))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Game>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(games: &Self::Input<'_>) -> u32 {
+        let bag = Cubes::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+        games
+            .iter()
+            .filter(|game| game.possible_with(&bag))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part_b(games: &Self::Input<'_>) -> BigUint {
+        games.iter().map(|game| game.min_bag().power()).sum()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day2::parse(include_str!("../../in/2/ex")).unwrap();
+    assert_eq!(Day2::part_a(&ex), 8);
+    assert_eq!(Day2::part_b(&ex), 2286u32.into());
+
+    assert_eq!(ex[0].min_bag().to_string(), "6 blue, 2 green, 4 red");
+    let bag: Cubes = "4 red, 6 blue, 2 green, 1 purple".parse().unwrap();
+    assert!(ex[0].possible_with(&bag));
+    assert!(!ex[1].possible_with(&bag));
+    assert!("4 red,".parse::<Cubes>().is_err());
+    assert!("99999999999 red".parse::<Cubes>().is_err());
+    let empty: Cubes = "0 purple".parse().unwrap();
+    assert!(bag.covers(&empty));
+
+    let no_blue = Day2::parse("Game 1: 3 red, 2 green\n").unwrap();
+    assert_eq!(Day2::part_b(&no_blue), 0u32.into());
+    let huge: Cubes = "4294967295 red, 2 green, 3 blue".parse().unwrap();
+    assert_eq!(huge.power(), BigUint::from(u32::MAX) * 6u32);
+}
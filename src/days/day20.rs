@@ -1,17 +1,16 @@
+use anyhow::{anyhow, ensure, Context};
 use std::{
     collections::{BTreeMap, BTreeSet, VecDeque},
     fmt,
     ops::Not,
 };
 
-use aoc23::read_stdin_to_string;
 use graphviz_rust::{
     dot_generator::*,
     dot_structures::*,
     printer::{DotPrinter, PrinterContext},
 };
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
 use strum::EnumIs;
 use winnow::{
     ascii::{line_ending, space0},
@@ -21,10 +20,13 @@ use winnow::{
     token::take_while,
 };
 
+use crate::{cycle, winnow_parse, Solution};
+
 type Tag<'a> = &'a str;
+type Network<'a> = Vec<(Tag<'a>, Module<'a>, Vec<Tag<'a>>)>;
 
 #[derive(Debug, Clone, Copy, EnumIs, PartialEq, Eq, Hash)]
-enum PulseValue {
+pub enum PulseValue {
     Low,
     High,
 }
@@ -53,7 +55,7 @@ impl Not for PulseValue {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum Module<'a> {
+pub enum Module<'a> {
     Broadcaster,
     FlipFlop(PulseValue),
     Conjunction(BTreeMap<Tag<'a>, PulseValue>),
@@ -73,8 +75,6 @@ struct ModuleWithOutputs<'a> {
     outputs: Vec<Tag<'a>>,
 }
 
-const DEBUG: bool = false;
-
 type ModGraph<'a> = BTreeMap<&'a str, ModuleWithOutputs<'a>>;
 
 fn build_graph<'a>(input: &'a [(Tag, Module, Vec<Tag>)]) -> ModGraph<'a> {
@@ -133,10 +133,6 @@ fn resolve_pulse<'a>(
     });
 
     while let Some(pulse) = queue.pop_front() {
-        if DEBUG {
-            println!("{} -{}-> {}", pulse.source, pulse.value, pulse.target);
-        }
-
         on_pulse(pulse);
 
         if let Some(node) = graph.get_mut(&pulse.target) {
@@ -164,10 +160,6 @@ fn resolve_pulse<'a>(
 fn solve_a(input: &[(Tag, Module, Vec<Tag>)]) -> u64 {
     let mut graph = build_graph(input);
 
-    if DEBUG {
-        println!("{graph:#?}");
-    }
-
     let mut queue = VecDeque::new();
     let (mut n_low, mut n_high) = (0, 0);
 
@@ -185,23 +177,46 @@ fn solve_a(input: &[(Tag, Module, Vec<Tag>)]) -> u64 {
         );
     }
 
-    if DEBUG {
-        println!("({n_low}, {n_high})");
-    }
-
     n_low * n_high
 }
 
-fn solve_b(input: &[(Tag, Module, Vec<Tag>)]) -> u64 {
+/// Sends a low pulse from the broadcaster to `start`, returning the new state and the pulses that
+/// reach `end`, with their depths.
+fn press_button<'a>(
+    graph: &ModGraph<'a>,
+    start: Tag<'a>,
+    end: Tag<'a>,
+    queue: &mut VecDeque<Pulse<'a>>,
+) -> (ModGraph<'a>, Vec<(PulseValue, u32)>) {
+    let mut graph = graph.clone();
+    let mut pulses = Vec::new();
+    resolve_pulse(
+        "broadcaster",
+        start,
+        PulseValue::Low,
+        &mut graph,
+        queue,
+        |pulse| {
+            if pulse.target == end {
+                pulses.push((pulse.value, pulse.depth));
+            }
+        },
+    );
+    (graph, pulses)
+}
+
+fn solve_b(input: &[(Tag, Module, Vec<Tag>)]) -> anyhow::Result<u64> {
     let graph = build_graph(input);
-    let starts = graph["broadcaster"].outputs.iter().copied().collect_vec();
+    let starts = graph
+        .get("broadcaster")
+        .context("no broadcaster module")?
+        .outputs
+        .clone();
     let end = "hb";
 
     let mut periods = Vec::new();
 
     for start in starts {
-        dbg!(start);
-
         let mut partition = BTreeSet::new();
         partition.insert(start);
 
@@ -219,78 +234,59 @@ fn solve_b(input: &[(Tag, Module, Vec<Tag>)]) -> u64 {
             partition.extend(new.drain(..));
         }
 
-        dbg!(&partition);
-
-        let mut graph = graph
+        let graph = graph
             .iter()
             .filter(|(tag, _)| partition.contains(*tag))
             .map(|(tag, module)| (*tag, module.clone()))
             .collect::<ModGraph>();
 
-        let mut seen = FxHashMap::default();
         let mut queue = VecDeque::new();
-        let mut pattern = Vec::new();
-        let period_start = loop {
-            match seen.insert(graph.clone(), seen.len()) {
-                None => (),
-                Some(old) => break old,
-            }
-
-            let mut pulses = Vec::new();
-            resolve_pulse(
-                "broadcaster",
-                start,
-                PulseValue::Low,
-                &mut graph,
-                &mut queue,
-                |pulse| {
-                    if pulse.target == end {
-                        pulses.push((pulse.value, pulse.depth));
-                    }
-                },
-            );
-
-            pattern.push(pulses);
-        };
-
+        let cycle = cycle::brent(graph.clone(), |graph| {
+            press_button(graph, start, end, &mut queue).0
+        });
+        let pattern = (0..cycle.start + cycle.period)
+            .scan(graph, |graph, _| {
+                let (next, pulses) = press_button(graph, start, end, &mut queue);
+                *graph = next;
+                Some(pulses)
+            })
+            .collect_vec();
+
+        // Each counter sends `end` a single high pulse, on the last button press of its cycle.
+        let unexpected = || format!("the counter behind {start} doesn't have the expected shape");
         let (high_i, high_v) = pattern
             .into_iter()
             .enumerate()
             .filter(|(_, v)| v.iter().any(|(value, _)| value.is_high()))
             .exactly_one()
-            .unwrap();
+            .map_err(|_| anyhow!(unexpected()))?;
 
-        assert!(high_v.len() > 1);
+        ensure!(high_v.len() > 1, unexpected());
 
         let (_, high_depth) = high_v
             .into_iter()
             .filter(|(value, _)| value.is_high())
             .exactly_one()
-            .unwrap();
-
-        let period = seen.len() - period_start;
+            .map_err(|_| anyhow!(unexpected()))?;
 
-        dbg!(period_start);
-        dbg!(period);
-        dbg!(high_i);
-        dbg!(high_depth);
+        let period = cycle.period;
 
-        assert_eq!(period_start, 1);
-        assert_eq!(high_depth, 3);
-        assert_eq!(high_i, period - 1);
+        ensure!(
+            cycle.start == 1 && high_depth == 3 && high_i == period - 1,
+            unexpected()
+        );
 
         periods.push(period);
     }
 
-    dbg!(&periods);
-
-    periods.into_iter().map(|p| p as u64).product::<u64>()
+    Ok(periods.into_iter().map(|p| p as u64).product::<u64>())
 }
 
-fn print_dot(input: &[(Tag, Module, Vec<Tag>)]) {
+pub fn dot(input: &str, _args: &[&str]) -> anyhow::Result<String> {
+    let input = winnow_parse(parser, input)?;
     let mut graph = graph!(strict di id!("g"));
 
-    for (tag, module, outputs) in input {
+    for (tag, module, outputs) in &input {
         let &tag = tag;
         let label = match module {
             Module::Broadcaster => r#""\N""#,
@@ -303,37 +299,28 @@ fn print_dot(input: &[(Tag, Module, Vec<Tag>)]) {
         }
     }
 
-    let dot = graph.print(&mut PrinterContext::default());
-    println!("{}", dot);
+    Ok(graph.print(&mut PrinterContext::default()))
 }
 
-fn solve(input: &str) {
-    let args = std::env::args().collect_vec();
-    let args = args.iter().map(|s| s.as_str()).collect_vec();
+pub struct Day20;
 
-    let input = parser.parse(input).unwrap();
+impl Solution for Day20 {
+    type Input<'a> = Network<'a>;
 
-    match &args[1..] {
-        ["a"] => {
-            let a = solve_a(&input);
-            println!("a: {a}");
-        }
-        ["dot"] => {
-            print_dot(&input);
-        }
-        ["b"] => {
-            let b = solve_b(&input);
-            println!("b: {b}");
-        }
-        _ => panic!("invalid arguments"),
+    fn parse(input: &str) -> anyhow::Result<Network<'_>> {
+        Ok(winnow_parse(parser, input)?)
+    }
+
+    fn part_a(input: &Self::Input<'_>) -> u64 {
+        solve_a(input)
     }
-}
 
-fn main() {
-    solve(read_stdin_to_string().as_str());
+    fn part_b(input: &Self::Input<'_>) -> anyhow::Result<u64> {
+        solve_b(input)
+    }
 }
 
-fn parser<'a>(input: &mut &'a str) -> PResult<Vec<(Tag<'a>, Module<'a>, Vec<Tag<'a>>)>> {
+fn parser<'a>(input: &mut &'a str) -> PResult<Network<'a>> {
     let tag =
         |input: &mut &'a str| take_while(1.., |c: char| c.is_ascii_lowercase()).parse_next(input);
 
@@ -370,5 +357,6 @@ fn test() {
     assert_eq!(solve_a(&ex2), 11687500);
     assert_eq!(solve_a(&input), 684125385);
 
-    assert_eq!(solve_b(&input), 225872806380073);
+    assert_eq!(solve_b(&input).unwrap(), 225872806380073);
+    assert!(solve_b(&ex).is_err());
 }
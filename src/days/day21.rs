@@ -0,0 +1,268 @@
+use anyhow::{ensure, Context};
+use rustc_hash::FxHashMap;
+use strum::Display;
+use winnow::{
+    combinator::{dispatch, fail, success},
+    prelude::*,
+    token::any,
+};
+
+use crate::{grid, search, winnow_parse, Delta, Dir, Grid, Pos, Solution};
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    #[strum(to_string = ".")]
+    Empty,
+
+    #[strum(to_string = "#")]
+    Rock,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TileOrStart {
+    Tile(Tile),
+    Start,
+}
+
+type Map = Grid<Tile>;
+
+#[derive(Debug, Clone, Copy)]
+struct ChunkPos {
+    chunk: (i32, i32),
+    tile: (i32, i32),
+}
+
+impl ChunkPos {
+    fn offset(self, delta: Delta, size: (usize, usize)) -> ChunkPos {
+        let (dy, dx) = (delta.row as i32, delta.col as i32);
+        ChunkPos {
+            chunk: (
+                self.chunk.0 + (self.tile.0 + dy).div_euclid(size.0 as i32),
+                self.chunk.1 + (self.tile.1 + dx).div_euclid(size.1 as i32),
+            ),
+            tile: (
+                (self.tile.0 + dy).rem_euclid(size.0 as i32),
+                (self.tile.1 + dx).rem_euclid(size.1 as i32),
+            ),
+        }
+    }
+}
+
+enum Chunk {
+    Partial(Box<(Grid<u32>, u32)>),
+    Finished,
+}
+
+struct MapState {
+    map: Map,
+    chunks: FxHashMap<(i32, i32), Chunk>,
+    frontier: Vec<ChunkPos>,
+    buf: Vec<ChunkPos>,
+    depth: u32,
+    finished: Grid<bool>,
+    finished_n: u32,
+    n_odd: u64,
+    n_even: u64,
+}
+
+impl MapState {
+    fn get(&self, pos: ChunkPos) -> (Tile, bool) {
+        let tile_pos = (pos.tile.0 as usize, pos.tile.1 as usize);
+        let tile = self.map[tile_pos];
+
+        let visited = match self.chunks.get(&pos.chunk) {
+            Some(Chunk::Partial(chunk)) => chunk.0[tile_pos] != 0,
+            Some(Chunk::Finished) => self.finished[tile_pos],
+            None => false,
+        };
+
+        (tile, visited)
+    }
+
+    fn set(&mut self, pos: ChunkPos, val: u32) {
+        let chunk = self.chunks.entry(pos.chunk).or_insert_with(|| {
+            Chunk::Partial(Box::new((
+                Grid::new(self.map.nrows(), self.map.ncols(), 0),
+                0,
+            )))
+        });
+        match chunk {
+            Chunk::Partial(p_chunk) => {
+                p_chunk.1 += 1;
+                if p_chunk.1 == self.finished_n {
+                    *chunk = Chunk::Finished;
+                } else {
+                    p_chunk.0[(pos.tile.0 as usize, pos.tile.1 as usize)] = val;
+                }
+            }
+            Chunk::Finished => unreachable!(),
+        }
+    }
+
+    fn step(&mut self) {
+        self.depth += 1;
+
+        let mut frontier = std::mem::take(&mut self.frontier);
+        for from in frontier.drain(..) {
+            for dir in Dir::ALL {
+                let neighbor = from.offset(dir.delta(), self.map.shape());
+                let (tile, visited) = self.get(neighbor);
+                if !visited && tile == Tile::Empty {
+                    self.set(neighbor, self.depth + 1);
+                    self.buf.push(neighbor);
+                    if self.depth.is_multiple_of(2) {
+                        self.n_even += 1;
+                    } else {
+                        self.n_odd += 1;
+                    }
+                }
+            }
+        }
+
+        self.frontier = std::mem::take(&mut self.buf);
+        self.buf = frontier;
+    }
+}
+
+fn open_neighbors(map: &Map, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    pos.neighbors4(map.shape())
+        .filter(|&neighbor| map[neighbor] == Tile::Empty)
+}
+
+fn find_reachable(map: &Map) -> (u32, Grid<bool>) {
+    let reachable = search::bfs_reach([Pos::new(0, 0)], |&pos| open_neighbors(map, pos));
+
+    let mut visited = map.map(|_| false);
+    for &pos in reachable.keys() {
+        visited[pos] = true;
+    }
+
+    (reachable.len() as u32, visited)
+}
+
+fn solve_a(map: &Map, start: Pos, steps: u64) -> usize {
+    search::bfs_reach([start], |&pos| open_neighbors(map, pos))
+        .into_values()
+        .filter(|&d| d as u64 <= steps && d as u64 & 1 == steps & 1)
+        .count()
+}
+
+/// The example asks for 6 steps, the real input for 64.
+fn step_count_a(map: &Map) -> u64 {
+    if map.nrows() < 64 {
+        6
+    } else {
+        64
+    }
+}
+
+fn solve_b(map: &Map, start: ChunkPos, steps: u32) -> u64 {
+    let (finished_n, finished) = find_reachable(map);
+
+    let mut state = MapState {
+        map: map.clone(),
+        chunks: FxHashMap::default(),
+        frontier: vec![start],
+        buf: vec![],
+        depth: 0,
+        finished,
+        finished_n,
+        n_odd: 0,
+        n_even: 0,
+    };
+
+    state.set(start, 1);
+    state.n_even += 1;
+
+    for _ in 0..steps {
+        state.step();
+    }
+
+    if steps.is_multiple_of(2) {
+        state.n_even
+    } else {
+        state.n_odd
+    }
+}
+
+/// The example lists answers for step counts up to 5000, the real input asks for 26501365.
+fn step_count_b(map: &Map) -> u32 {
+    if map.nrows() < 64 {
+        5000
+    } else {
+        26501365
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = (Map, Pos);
+
+    fn parse(input: &str) -> anyhow::Result<(Map, Pos)> {
+        resolve_start(winnow_parse(parser, input)?)
+    }
+
+    fn part_a((map, start): &Self::Input<'_>) -> usize {
+        solve_a(map, *start, step_count_a(map))
+    }
+
+    fn part_b((map, start): &Self::Input<'_>) -> u64 {
+        let start = ChunkPos {
+            chunk: (0, 0),
+            tile: (start.row as i32, start.col as i32),
+        };
+
+        solve_b(map, start, step_count_b(map))
+    }
+}
+
+fn parser(input: &mut &str) -> PResult<Grid<TileOrStart>> {
+    let tile = dispatch! {any;
+        '.' => success(TileOrStart::Tile(Tile::Empty)),
+        '#' => success(TileOrStart::Tile(Tile::Rock)),
+        'S' => success(TileOrStart::Start),
+        _ => fail,
+    };
+    grid(tile).parse_next(input)
+}
+
+/// Replaces the single start location with an empty tile.
+fn resolve_start(map: Grid<TileOrStart>) -> anyhow::Result<(Map, Pos)> {
+    let mut start = None;
+    let mut multiple = false;
+    let map = map.map_indexed(|pos, &tile_or_start| match tile_or_start {
+        TileOrStart::Tile(tile) => tile,
+        TileOrStart::Start => {
+            multiple |= start.replace(Pos::from(pos)).is_some();
+            Tile::Empty
+        }
+    });
+
+    ensure!(!multiple, "multiple start locations");
+    let start = start.context("no start location")?;
+    Ok((map, start))
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let (ex_map, ex_start) = Day21::parse(include_str!("../../in/21/ex")).unwrap();
+    let (i_map, i_start) = Day21::parse(include_str!("../../in/21/i")).unwrap();
+
+    assert_eq!(solve_a(&ex_map, ex_start, 6), 16);
+    assert_eq!(solve_a(&i_map, i_start, 64), 3768);
+
+    let ex_start = ChunkPos {
+        chunk: (0, 0),
+        tile: (ex_start.row as i32, ex_start.col as i32),
+    };
+
+    assert_eq!(solve_b(&ex_map, ex_start, 6), 16);
+    assert_eq!(solve_b(&ex_map, ex_start, 10), 50);
+    assert_eq!(solve_b(&ex_map, ex_start, 50), 1594);
+    assert_eq!(solve_b(&ex_map, ex_start, 100), 6536);
+    assert_eq!(solve_b(&ex_map, ex_start, 500), 167004);
+    assert_eq!(solve_b(&ex_map, ex_start, 1000), 668697);
+    assert_eq!(solve_b(&ex_map, ex_start, 5000), 16733044);
+}
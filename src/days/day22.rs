@@ -1,6 +1,5 @@
 use std::fmt::Debug;
 
-use aoc23::read_stdin_to_string;
 use fixedbitset::FixedBitSet;
 use winnow::{
     ascii::{dec_uint, line_ending},
@@ -9,10 +8,12 @@ use winnow::{
     seq,
 };
 
+use crate::{winnow_parse, Solution};
+
 type Pos = [u16; 3];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Brick(Pos, Pos);
+pub struct Brick(Pos, Pos);
 
 impl Brick {
     fn iter(self) -> impl Iterator<Item = Pos> {
@@ -23,18 +24,13 @@ impl Brick {
     }
 
     fn lower(self) -> Option<Self> {
-        // println!("trying to lower");
-
         let Brick(mut a, mut b) = self;
         if a[2] >= 2 {
-            // println!("not at bottom");
             assert!(b[2] >= 2);
             a[2] -= 1;
             b[2] -= 1;
-            // println!("new: {:?}", Brick(a, b));
             Some(Brick(a, b))
         } else {
-            // println!("at bottom");
             None
         }
     }
@@ -167,7 +163,7 @@ impl Debug for Grid {
     }
 }
 
-fn solve(mut bricks: Vec<Brick>) -> (u64, u64) {
+fn settle(mut bricks: Vec<Brick>) -> (Grid, Vec<Brick>) {
     let mut max = [0; 3];
     for &Brick(a, b) in &bricks {
         for i in 0..3 {
@@ -184,6 +180,12 @@ fn solve(mut bricks: Vec<Brick>) -> (u64, u64) {
 
     grid.settle_bricks(&mut bricks, None, |_| ());
 
+    (grid, bricks)
+}
+
+fn solve_a(bricks: &[Brick]) -> u64 {
+    let (mut grid, bricks) = settle(bricks.to_vec());
+
     let mut a_sum = 0;
     for &brick in &bricks {
         grid.remove_brick(brick);
@@ -195,6 +197,12 @@ fn solve(mut bricks: Vec<Brick>) -> (u64, u64) {
         grid.add_brick(brick);
     }
 
+    a_sum
+}
+
+fn solve_b(bricks: &[Brick]) -> u64 {
+    let (grid, bricks) = settle(bricks.to_vec());
+
     let mut b_sum = 0;
     let mut would_fall = vec![false; bricks.len()];
 
@@ -210,15 +218,25 @@ fn solve(mut bricks: Vec<Brick>) -> (u64, u64) {
         would_fall.fill(false);
     }
 
-    (a_sum, b_sum)
+    b_sum
 }
 
-fn main() {
-    let input = parser.parse(read_stdin_to_string().as_str()).unwrap();
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<Brick>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Brick>> {
+        Ok(winnow_parse(parser, input)?)
+    }
 
-    let (a, b) = solve(input);
-    println!("a: {a}");
-    println!("b: {b}");
+    fn part_a(bricks: &Self::Input<'_>) -> u64 {
+        solve_a(bricks)
+    }
+
+    fn part_b(bricks: &Self::Input<'_>) -> u64 {
+        solve_b(bricks)
+    }
 }
 
 fn parser(input: &mut &str) -> PResult<Vec<Brick>> {
@@ -250,11 +268,9 @@ fn test() {
     let ex = parser.parse(include_str!("../../in/22/ex")).unwrap();
     let i = parser.parse(include_str!("../../in/22/i")).unwrap();
 
-    let (a_ex, b_ex) = solve(ex);
-    assert_eq!(a_ex, 5);
-    assert_eq!(b_ex, 7);
+    assert_eq!(solve_a(&ex), 5);
+    assert_eq!(solve_b(&ex), 7);
 
-    let (a_i, b_i) = solve(i);
-    assert_eq!(a_i, 482);
-    assert_eq!(b_i, 103010);
+    assert_eq!(solve_a(&i), 482);
+    assert_eq!(solve_b(&i), 103010);
 }
@@ -1,18 +1,18 @@
 use std::collections::VecDeque;
 
-use aoc23::{bounded_offset, matrix, read_stdin_to_string};
 use itertools::Itertools;
-use nalgebra::DMatrix;
-use strum::{Display, EnumIter, IntoEnumIterator};
+use strum::Display;
 use winnow::{
     combinator::{dispatch, fail, success},
     prelude::*,
     token::any,
 };
 
+use crate::{grid, winnow_parse, Dir, Grid, Pos, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[rustfmt::skip]
-enum Tile {
+pub enum Tile {
     Empty,    
     Blocked,
     Slope(Dir),
@@ -44,37 +44,9 @@ impl std::fmt::Display for Tile {
     }
 }
 
-#[derive(Debug, Display, EnumIter, Clone, Copy, PartialEq, Eq)]
-#[rustfmt::skip]
-enum Dir {
-    #[strum(serialize="v")] Down,
-    #[strum(serialize="^")] Up,
-    #[strum(serialize=">")] Right,
-    #[strum(serialize="<")] Left,
-}
-
-impl Dir {
-    fn delta(self) -> (isize, isize) {
-        match self {
-            Dir::Down => (1, 0),
-            Dir::Up => (-1, 0),
-            Dir::Right => (0, 1),
-            Dir::Left => (0, -1),
-        }
-    }
-    fn reverse(self) -> Self {
-        match self {
-            Dir::Down => Dir::Up,
-            Dir::Up => Dir::Down,
-            Dir::Right => Dir::Left,
-            Dir::Left => Dir::Right,
-        }
-    }
-}
-
-fn find_start_and_end(map: &DMatrix<Tile>) -> ((usize, usize), (usize, usize)) {
+fn find_start_and_end(map: &Grid<Tile>) -> (Pos, Pos) {
     let [start, end] = [0, map.nrows() - 1].map(|row| {
-        (
+        Pos::new(
             row,
             map.row(row)
                 .iter()
@@ -86,9 +58,7 @@ fn find_start_and_end(map: &DMatrix<Tile>) -> ((usize, usize), (usize, usize)) {
     (start, end)
 }
 
-fn solve_a(map: &DMatrix<Tile>) -> u32 {
-    println!("{map}");
-
+fn solve_a(map: &Grid<Tile>) -> u32 {
     let (start, end) = find_start_and_end(map);
 
     let mut frontier = VecDeque::new();
@@ -101,13 +71,12 @@ fn solve_a(map: &DMatrix<Tile>) -> u32 {
             continue;
         }
 
-        Dir::iter()
+        Dir::ALL
+            .into_iter()
             .filter(|&dir| {
                 dir != last_dir.reverse() && [Tile::Empty, Tile::Slope(dir)].contains(&map[cur])
             })
-            .filter_map(|dir| {
-                bounded_offset(cur, dir.delta(), map.shape()).map(|neighbor| (neighbor, dir))
-            })
+            .filter_map(|dir| cur.step(dir, map.shape()).map(|neighbor| (neighbor, dir)))
             .for_each(|(neighbor, dir)| {
                 let dist = longest[cur] + 1;
                 if dist > longest[neighbor] {
@@ -122,15 +91,14 @@ fn solve_a(map: &DMatrix<Tile>) -> u32 {
 
 #[derive(Debug)]
 enum Node {
-    Backtrack((usize, usize)),
-    Visit((usize, usize)),
+    Backtrack(Pos),
+    Visit(Pos),
 }
 
-fn solve_b(map: &DMatrix<Tile>) -> u32 {
+fn solve_b(map: &Grid<Tile>) -> u32 {
     let (start, end) = find_start_and_end(map);
 
-    let map = map.map(TileB::from);
-    println!("{map}");
+    let map = map.map(|&tile| TileB::from(tile));
 
     let mut visited = map.map(|_| false);
     let mut stack = vec![Node::Visit(start)];
@@ -150,14 +118,10 @@ fn solve_b(map: &DMatrix<Tile>) -> u32 {
                 stack.push(Node::Backtrack(cur));
 
                 if cur == end {
-                    if dist > longest {
-                        println!("{longest}");
-                    }
                     longest = longest.max(dist);
                 } else {
                     stack.extend(
-                        Dir::iter()
-                            .filter_map(|dir| bounded_offset(cur, dir.delta(), map.shape()))
+                        cur.neighbors4(map.shape())
                             .filter(|&neighbor| !visited[neighbor] && map[neighbor] == TileB::Empty)
                             .map(Node::Visit),
                     );
@@ -169,16 +133,25 @@ fn solve_b(map: &DMatrix<Tile>) -> u32 {
     longest - 1
 }
 
-fn main() {
-    let map = parser.parse(read_stdin_to_string().as_str()).unwrap();
-    let a = solve_a(&map);
-    println!("a: {a}");
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Grid<Tile>;
 
-    let b = solve_b(&map);
-    println!("b: {b}");
+    fn parse(input: &str) -> anyhow::Result<Grid<Tile>> {
+        Ok(winnow_parse(parser, input)?)
+    }
+
+    fn part_a(map: &Self::Input<'_>) -> u32 {
+        solve_a(map)
+    }
+
+    fn part_b(map: &Self::Input<'_>) -> u32 {
+        solve_b(map)
+    }
 }
 
-fn parser(input: &mut &str) -> PResult<DMatrix<Tile>> {
+fn parser(input: &mut &str) -> PResult<Grid<Tile>> {
     let tile = dispatch!(any;
         '.' => success(Tile::Empty),
         '#' => success(Tile::Blocked),
@@ -188,7 +161,7 @@ fn parser(input: &mut &str) -> PResult<DMatrix<Tile>> {
         '<' => success(Tile::Slope(Dir::Left)),
         _ => fail,
     );
-    matrix(tile).parse_next(input)
+    grid(tile).parse_next(input)
 }
 
 #[cfg(test)]
@@ -1,6 +1,5 @@
-use std::ops::RangeBounds;
+use std::ops::{RangeBounds, RangeInclusive};
 
-use aoc23::read_stdin_to_string;
 use nalgebra::{vector, Matrix2, Vector2, Vector3};
 use num::{rational::Ratio, Zero};
 use winnow::{
@@ -10,8 +9,10 @@ use winnow::{
     seq,
 };
 
+use crate::{winnow_parse, Part, Solution};
+
 #[derive(Debug, Clone, Copy)]
-struct Hailstone {
+pub struct Hailstone {
     pos: Vector3<i128>,
     vel: Vector3<i128>,
 }
@@ -74,17 +75,29 @@ fn solve_a(stones: &[Hailstone], bounds: impl RangeBounds<Ratio<i128>>) -> usize
         .count()
 }
 
-fn main() {
-    let stones = parser.parse(read_stdin_to_string().as_str()).unwrap();
+/// The example uses a much smaller test area than the real input.
+fn test_area(stones: &[Hailstone]) -> RangeInclusive<Ratio<i128>> {
+    if stones.iter().all(|stone| stone.pos.abs().max() < 1000) {
+        Ratio::from(7)..=Ratio::from(27)
+    } else {
+        Ratio::from(200000000000000)..=Ratio::from(400000000000000)
+    }
+}
 
-    let a_small = solve_a(&stones, Ratio::from(7)..=Ratio::from(27));
-    println!("a_small: {a_small}");
+pub struct Day24;
 
-    let a_large = solve_a(
-        &stones,
-        Ratio::from(200000000000000)..=Ratio::from(400000000000000),
-    );
-    println!("a_large: {a_large}");
+impl Solution for Day24 {
+    type Input<'a> = Vec<Hailstone>;
+
+    const PARTS: &'static [Part] = &[Part::A];
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Hailstone>> {
+        Ok(winnow_parse(parser, input)?)
+    }
+
+    fn part_a(stones: &Self::Input<'_>) -> usize {
+        solve_a(stones, test_area(stones))
+    }
 }
 
 fn parser(input: &mut &str) -> PResult<Vec<Hailstone>> {
@@ -1,12 +1,5 @@
-#![feature(map_try_insert)]
+use std::{convert::identity, iter};
 
-use std::{
-    convert::identity,
-    iter,
-    sync::atomic::{AtomicUsize, Ordering},
-};
-
-use aoc23::read_stdin_to_string;
 use itertools::{chain, Itertools};
 use outils::prelude::*;
 use rayon::prelude::*;
@@ -19,21 +12,17 @@ use winnow::{
     token::take_while,
 };
 
+use crate::{winnow_parse, Part, Solution};
+
 type Input<'a> = Vec<(&'a str, Vec<&'a str>)>;
 
 fn solve_a(input: &Input) -> u64 {
     let mut vertices = FxHashMap::default();
-    let mut labels = Vec::new();
     input
         .iter()
         .flat_map(|(a, bs)| chain!(iter::once(*a), bs.iter().copied()))
         .for_each(|label| {
-            if vertices
-                .try_insert(label, VertexIndex(vertices.len()))
-                .is_ok()
-            {
-                labels.push(label);
-            }
+            let _ = vertices.try_insert(label, VertexIndex(vertices.len()));
         });
 
     let mut edges = Vec::new();
@@ -47,9 +36,6 @@ fn solve_a(input: &Input) -> u64 {
         }
     }
 
-    let print_edge = |e: Edge| println!("{}-{}", labels[e.src().0], labels[e.dst().0]);
-    let finished = AtomicUsize::new(0);
-
     let result = (0..edges.len())
         .into_par_iter()
         .map_with((graph, edges), |(graph, edges), i| {
@@ -60,35 +46,26 @@ fn solve_a(input: &Input) -> u64 {
                 graph.delete_edge(edges[j]);
                 assert!(graph.is_connected(edges[j].src(), edges[j].dst()));
 
-                for k in j + 1..edges.len() {
-                    graph.delete_edge(edges[k]);
-                    if !graph.is_connected(edges[k].src(), edges[k].dst()) {
-                        print_edge(edges[i]);
-                        print_edge(edges[j]);
-                        print_edge(edges[k]);
-
+                for edge in &mut edges[j + 1..] {
+                    graph.delete_edge(*edge);
+                    if !graph.is_connected(edge.src(), edge.dst()) {
                         let components = graph.components().collect_vec();
                         assert_eq!(components.len(), 2);
 
-                        println!("success: {}", finished.fetch_add(1, Ordering::Relaxed));
-
                         return Some(
                             components
                                 .into_iter()
                                 .map(|c| graph.component_vertices(c).count() as u64)
-                                .inspect(|n| println!("{n}"))
                                 .product::<u64>(),
                         );
                     }
 
-                    edges[k] = graph.insert_edge(edges[k].src(), edges[k].dst()).unwrap();
+                    *edge = graph.insert_edge(edge.src(), edge.dst()).unwrap();
                 }
                 edges[j] = graph.insert_edge(edges[j].src(), edges[j].dst()).unwrap();
             }
             edges[i] = graph.insert_edge(edges[i].src(), edges[i].dst()).unwrap();
 
-            println!("finish: {}", finished.fetch_add(1, Ordering::Relaxed));
-
             None
         })
         .find_map_any(identity);
@@ -96,11 +73,20 @@ fn solve_a(input: &Input) -> u64 {
     result.unwrap()
 }
 
-fn main() {
-    let input = read_stdin_to_string();
-    let input = parser.parse(input.as_str()).unwrap();
-    let a = solve_a(&input);
-    println!("a: {a}");
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Input<'a>;
+
+    const PARTS: &'static [Part] = &[Part::A];
+
+    fn parse(input: &str) -> anyhow::Result<Input<'_>> {
+        Ok(winnow_parse(parser, input)?)
+    }
+
+    fn part_a(input: &Self::Input<'_>) -> u64 {
+        solve_a(input)
+    }
 }
 
 fn parser<'a>(input: &mut &'a str) -> PResult<Input<'a>> {
@@ -0,0 +1,228 @@
+use std::fmt::Write;
+
+use anyhow::Context;
+use itertools::Itertools;
+use num::BigUint;
+use rustc_hash::FxHashMap;
+
+use crate::{Grid, Pos, Solution};
+
+/// A number written left to right across a row.
+#[derive(Debug, Clone, Copy)]
+pub struct Number {
+    /// The first digit.
+    pos: Pos,
+    len: usize,
+    value: u64,
+}
+
+impl Number {
+    fn cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.len).map(|i| Pos::new(self.pos.row, self.pos.col + i))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Symbol {
+    pos: Pos,
+    glyph: u8,
+}
+
+/// An engine schematic, with every number linked to the symbols next to it and vice versa.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    grid: Grid<u8>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// For each number, the indices of the symbols next to it.
+    number_symbols: Vec<Vec<usize>>,
+    /// For each symbol, the indices of the numbers next to it.
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn new(grid: Grid<u8>) -> anyhow::Result<Self> {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        for (row, cells) in grid.rows().enumerate() {
+            let mut col = 0;
+            while let Some(&glyph) = cells.get(col) {
+                let pos = Pos::new(row, col);
+                let len = cells[col..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .count();
+                if len > 0 {
+                    let digits = std::str::from_utf8(&cells[col..col + len])?;
+                    let value = digits
+                        .parse()
+                        .with_context(|| format!("number too large at {pos:?}"))?;
+                    numbers.push(Number { pos, len, value });
+                    col += len;
+                } else {
+                    if glyph != b'.' {
+                        symbols.push(Symbol { pos, glyph });
+                    }
+                    col += 1;
+                }
+            }
+        }
+
+        let symbol_at: FxHashMap<Pos, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| (symbol.pos, i))
+            .collect();
+
+        let mut symbol_numbers = vec![Vec::new(); symbols.len()];
+        let number_symbols = numbers
+            .iter()
+            .enumerate()
+            .map(|(i, number)| {
+                let adjacent = number
+                    .cells()
+                    .flat_map(|pos| pos.neighbors8(grid.shape()))
+                    .filter_map(|pos| symbol_at.get(&pos).copied())
+                    .sorted_unstable()
+                    .dedup()
+                    .collect_vec();
+                for &j in &adjacent {
+                    symbol_numbers[j].push(i);
+                }
+                adjacent
+            })
+            .collect();
+
+        Ok(Schematic {
+            grid,
+            numbers,
+            symbols,
+            number_symbols,
+            symbol_numbers,
+        })
+    }
+
+    /// The numbers next to at least one symbol.
+    pub fn parts(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+    }
+
+    /// The numbers next to no symbol at all.
+    pub fn loose_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(|(_, symbols)| symbols.is_empty())
+            .map(|(number, _)| number)
+    }
+
+    /// The symbols next to exactly `k` numbers, with the product of those numbers.
+    pub fn symbols_with(&self, k: usize) -> impl Iterator<Item = (&Symbol, BigUint)> {
+        self.symbols
+            .iter()
+            .zip(&self.symbol_numbers)
+            .filter(move |(_, numbers)| numbers.len() == k)
+            .map(|(symbol, numbers)| {
+                let product = numbers
+                    .iter()
+                    .map(|&i| BigUint::from(self.numbers[i].value))
+                    .product();
+                (symbol, product)
+            })
+    }
+
+    /// The `*` symbols next to exactly two numbers, with their gear ratios.
+    pub fn gears(&self) -> impl Iterator<Item = (&Symbol, BigUint)> {
+        self.symbols_with(2)
+            .filter(|(symbol, _)| symbol.glyph == b'*')
+    }
+}
+
+/// Prints the schematic with its parts in green, its gears in yellow and its other symbols in
+/// bold, leaving the loose numbers dim.
+pub fn render(input: &str, _args: &[&str]) -> anyhow::Result<String> {
+    const PART: &str = "\x1b[32m";
+    const GEAR: &str = "\x1b[1;33m";
+    const SYMBOL: &str = "\x1b[1m";
+    const LOOSE: &str = "\x1b[2m";
+    const RESET: &str = "\x1b[0m";
+
+    let schematic = Day3::parse(input)?;
+    let (nrows, ncols) = schematic.grid.shape();
+    let mut style = Grid::new(nrows, ncols, None);
+    for number in schematic.parts() {
+        for pos in number.cells() {
+            style[pos] = Some(PART);
+        }
+    }
+    for number in schematic.loose_numbers() {
+        for pos in number.cells() {
+            style[pos] = Some(LOOSE);
+        }
+    }
+    for symbol in &schematic.symbols {
+        style[symbol.pos] = Some(SYMBOL);
+    }
+    for (gear, _) in schematic.gears() {
+        style[gear.pos] = Some(GEAR);
+    }
+
+    let mut out = String::new();
+    for (cells, styles) in schematic.grid.rows().zip(style.rows()) {
+        for (&c, style) in cells.iter().zip(styles) {
+            match style {
+                Some(style) => write!(out, "{style}{}{RESET}", c as char)?,
+                None => out.push(c as char),
+            }
+        }
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> anyhow::Result<Schematic> {
+        let grid = Grid::from_rows(input.lines().map(|line| line.as_bytes().to_vec()).collect())?;
+        Schematic::new(grid)
+    }
+
+    fn part_a(schematic: &Self::Input<'_>) -> BigUint {
+        schematic
+            .parts()
+            .map(|number| BigUint::from(number.value))
+            .sum()
+    }
+
+    fn part_b(schematic: &Self::Input<'_>) -> BigUint {
+        schematic.gears().map(|(_, ratio)| ratio).sum()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day3::parse(include_str!("../../in/3/ex")).unwrap();
+    assert_eq!(Day3::part_a(&ex), 4361u32.into());
+    assert_eq!(Day3::part_b(&ex), 467835u32.into());
+
+    let loose = ex.loose_numbers().map(|number| number.value).collect_vec();
+    assert_eq!(loose, [114, 58]);
+    let lonely = ex.symbols_with(1).map(|(symbol, n)| (symbol.glyph, n));
+    assert_eq!(
+        lonely.collect_vec(),
+        [(b'#', 633u32), (b'*', 617), (b'+', 592), (b'$', 664)].map(|(g, n)| (g, n.into()))
+    );
+
+    let big = u64::MAX.to_string();
+    let huge = Day3::parse(&format!("{big}*{big}\n")).unwrap();
+    assert_eq!(Day3::part_a(&huge), BigUint::from(u64::MAX) * 2u32);
+    assert_eq!(Day3::part_b(&huge), BigUint::from(u64::MAX).pow(2));
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use anyhow::Context;
+use chumsky::prelude::*;
+use num::BigUint;
+use vecmap::VecSet;
+
+use crate::{chumsky_err, chumsky_parse, Solution};
+
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    winning: VecSet<u8>,
+    chosen: VecSet<u8>,
+}
+
+fn parser() -> impl Parser<char, Vec<Card>, Error = Simple<char>> {
+    let int = text::int(10).from_str::<u8>().try_map(chumsky_err).padded();
+    let id = text::int(10)
+        .from_str::<u32>()
+        .try_map(chumsky_err)
+        .padded();
+
+    let head = just("Card").ignore_then(id).then_ignore(just(':'));
+    let numbers = int.repeated();
+    let tail = numbers.then_ignore(just('|')).then(numbers);
+
+    head.then(tail)
+        .map(|(id, (w, c))| Card {
+            id,
+            winning: w.into(),
+            chosen: c.into(),
+        })
+        .repeated()
+        .then_ignore(end())
+}
+
+impl Card {
+    fn matches(&self) -> usize {
+        self.chosen
+            .iter()
+            .filter(|x| self.winning.contains(x))
+            .count()
+    }
+}
+
+/// How many copies of each card end up in the pile, original included.
+///
+/// A card's count is final once every card before it has been processed, so one pass suffices.
+fn copies(cards: &[Card]) -> Vec<BigUint> {
+    let mut copies = vec![BigUint::from(1u32); cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let (won, rest) = copies.split_at_mut(i + 1);
+        let n = &won[i];
+        for later in rest.iter_mut().take(card.matches()) {
+            *later += n;
+        }
+    }
+    copies
+}
+
+/// Prints the number of copies of every card.
+pub fn breakdown(input: &str, _args: &[&str]) -> anyhow::Result<String> {
+    let cards = Day4::parse(input)?;
+    let mut out = String::new();
+    for (card, n) in cards.iter().zip(copies(&cards)) {
+        writeln!(out, "Card {}: {n}", card.id)?;
+    }
+    Ok(out)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Card>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(cards: &Self::Input<'_>) -> anyhow::Result<u64> {
+        cards.iter().try_fold(0u64, |total, card| {
+            let points = match card.matches() {
+                0 => Some(0),
+                n => 1u64.checked_shl(n as u32 - 1),
+            };
+            points
+                .and_then(|points| total.checked_add(points))
+                .with_context(|| format!("the points overflow a u64 at card {}", card.id))
+        })
+    }
+
+    fn part_b(cards: &Self::Input<'_>) -> BigUint {
+        copies(cards).into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day4::parse(include_str!("../../in/4/ex")).unwrap();
+    let copies = copies(&ex)
+        .into_iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(copies, ["1", "2", "4", "8", "14", "1"]);
+    assert_eq!(Day4::part_a(&ex).unwrap(), 13);
+    assert_eq!(Day4::part_b(&ex), 30u32.into());
+
+    let numbers = (1..=65)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let card = |id: u64| format!("Card {id}: {numbers} | {numbers}\n");
+    let many = Day4::parse(&(card(299) + &card(300))).unwrap();
+    assert_eq!(Day4::part_b(&many), 3u32.into());
+    assert!(Day4::part_a(&many).is_err());
+    assert!(Day4::parse(&card(99999999999)).is_err());
+}
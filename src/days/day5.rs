@@ -0,0 +1,299 @@
+use std::{
+    cmp::{max, min},
+    fmt::{self, Write},
+    ops::Range,
+};
+
+use anyhow::{bail, ensure, Context};
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::{chumsky_err, chumsky_parse, IntervalSet, Solution};
+
+type Seeds = Vec<u64>;
+type RangeMap = Vec<(Range<u64>, i64)>;
+
+fn parser() -> impl Parser<char, (Seeds, Vec<RangeMap>), Error = Simple<char>> {
+    let int = text::int(10)
+        .from_str::<u64>()
+        .try_map(chumsky_err)
+        .padded();
+
+    let seeds = just("seeds:").padded().ignore_then(int.repeated());
+
+    let map_label = filter(|&c: &char| c.is_ascii_alphabetic() || c == '-')
+        .ignored()
+        .repeated()
+        .then_ignore(just(" map:"));
+
+    let range = int
+        .then(int)
+        .then(int)
+        .map(|((dst_start, src_start), len)| {
+            (
+                src_start..src_start + len,
+                dst_start as i64 - src_start as i64,
+            )
+        });
+
+    let map = map_label.ignore_then(range.repeated());
+
+    seeds.then(map.repeated()).then_ignore(end())
+}
+
+fn shift(x: u64, offset: i64) -> u64 {
+    x.checked_add_signed(offset)
+        .expect("offset moved a value out of range")
+}
+
+/// A function on `u64` that adds a constant offset on each of a number of ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PiecewiseMap {
+    /// Sorted and covering the whole domain, with no two touching segments sharing an offset.
+    segments: Vec<(Range<u64>, i64)>,
+}
+
+impl PiecewiseMap {
+    fn identity() -> Self {
+        PiecewiseMap {
+            segments: vec![(0..u64::MAX, 0)],
+        }
+    }
+
+    /// The function described by one almanac map, which leaves values outside its ranges alone.
+    fn from_ranges(map: &RangeMap) -> Self {
+        let mut segments = Vec::new();
+        let mut end = 0;
+        for (range, offset) in map.iter().sorted_by_key(|(r, _)| r.start) {
+            segments.push((end..range.start, 0));
+            segments.push((range.clone(), *offset));
+            end = range.end;
+        }
+        segments.push((end..u64::MAX, 0));
+        Self::normalized(segments)
+    }
+
+    fn normalized(segments: Vec<(Range<u64>, i64)>) -> Self {
+        let segments = segments
+            .into_iter()
+            .filter(|(r, _)| !r.is_empty())
+            .sorted_by_key(|(r, _)| r.start)
+            .coalesce(|(a, a_off), (b, b_off)| {
+                if a.end == b.start && a_off == b_off {
+                    Ok((a.start..b.end, a_off))
+                } else {
+                    Err(((a, a_off), (b, b_off)))
+                }
+            })
+            .collect();
+        PiecewiseMap { segments }
+    }
+
+    /// The segments that overlap `range`.
+    fn overlapping(&self, range: &Range<u64>) -> impl Iterator<Item = &(Range<u64>, i64)> {
+        let i = self.segments.partition_point(|(r, _)| r.end <= range.start);
+        let end = range.end;
+        self.segments[i..]
+            .iter()
+            .take_while(move |(r, _)| r.start < end)
+    }
+
+    fn apply(&self, x: u64) -> u64 {
+        let (_, offset) = self.overlapping(&(x..x + 1)).next().unwrap();
+        shift(x, *offset)
+    }
+
+    /// The function that applies `self`, then `next`.
+    fn then(&self, next: &Self) -> Self {
+        let mut segments = Vec::new();
+        for (range, offset) in &self.segments {
+            let image = shift(range.start, *offset)..shift(range.end, *offset);
+            for (next_range, next_offset) in next.overlapping(&image) {
+                let start = max(image.start, next_range.start);
+                let end = min(image.end, next_range.end);
+                segments.push((
+                    shift(start, -offset)..shift(end, -offset),
+                    offset + next_offset,
+                ));
+            }
+        }
+        Self::normalized(segments)
+    }
+
+    /// Where the values of `set` end up.
+    fn image(&self, set: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut ranges = Vec::new();
+        for (range, offset) in &self.segments {
+            for r in set.intersection(&IntervalSet::from(range.clone())).ranges() {
+                ranges.push(shift(r.start, *offset)..shift(r.end, *offset));
+            }
+        }
+        ranges.into_iter().collect()
+    }
+
+    /// The values that end up in `set`.
+    fn inverse_image(&self, set: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut ranges = Vec::new();
+        for (range, offset) in &self.segments {
+            let image = shift(range.start, *offset)..shift(range.end, *offset);
+            for r in set.intersection(&IntervalSet::from(image)).ranges() {
+                ranges.push(shift(r.start, -offset)..shift(r.end, -offset));
+            }
+        }
+        ranges.into_iter().collect()
+    }
+}
+
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (range, offset) in &self.segments {
+            let image = shift(range.start, *offset)..shift(range.end, *offset);
+            writeln!(f, "{range:?} -> {image:?} ({offset:+})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Every map of the almanac composed into one, from seed to location.
+fn seed_to_location(maps: &[RangeMap]) -> PiecewiseMap {
+    maps.iter()
+        .map(PiecewiseMap::from_ranges)
+        .fold(PiecewiseMap::identity(), |f, g| f.then(&g))
+}
+
+fn seed_ranges(seeds: &Seeds) -> anyhow::Result<IntervalSet<u64>> {
+    ensure!(
+        seeds.len().is_multiple_of(2),
+        "the {} seeds don't pair up into ranges",
+        seeds.len()
+    );
+    seeds
+        .iter()
+        .tuples()
+        .map(|(&start, &len)| {
+            let end = start
+                .checked_add(len)
+                .with_context(|| format!("the seed range {start} {len} is out of range"))?;
+            Ok(start..end)
+        })
+        .collect()
+}
+
+/// Prints the segments of the composed seed-to-location map.
+pub fn segments(input: &str, _args: &[&str]) -> anyhow::Result<String> {
+    let (_, maps) = Day5::parse(input)?;
+    let mut out = String::new();
+    write!(out, "{}", seed_to_location(&maps))?;
+    Ok(out)
+}
+
+/// Parses a location, or a range of them written `start..end`.
+fn location_arg(arg: &str) -> anyhow::Result<Range<u64>> {
+    let parse = |n: &str| {
+        n.parse::<u64>()
+            .with_context(|| format!("invalid location: {n}"))
+    };
+    let range = match arg.split_once("..") {
+        Some((start, end)) => parse(start)?..parse(end)?,
+        None => {
+            let loc = parse(arg)?;
+            loc..loc.checked_add(1).context("location out of range")?
+        }
+    };
+    ensure!(range.start < range.end, "empty location range: {arg}");
+    Ok(range)
+}
+
+/// Prints the seeds that land on the lowest location, for both readings of the seed list, or on
+/// the location or range of locations given.
+pub fn origins(input: &str, args: &[&str]) -> anyhow::Result<String> {
+    let (seeds, maps) = Day5::parse(input)?;
+    let f = seed_to_location(&maps);
+
+    let mut out = String::new();
+    match args {
+        [] => {
+            let single: IntervalSet<u64> = seeds.iter().map(|&seed| seed..seed + 1).collect();
+            for (part, seeds) in [("a", single), ("b", seed_ranges(&seeds)?)] {
+                let loc = f.image(&seeds).min().context("no seeds")?;
+                let origins = f.inverse_image(&IntervalSet::from(loc..loc + 1));
+                let origins = origins.intersection(&seeds);
+                writeln!(
+                    out,
+                    "{part}: location {loc} <- seeds {:?}",
+                    origins.ranges()
+                )?;
+            }
+        }
+        [arg] => {
+            let locations = location_arg(arg)?;
+            let origins = f.inverse_image(&IntervalSet::from(locations.clone()));
+            writeln!(
+                out,
+                "locations {locations:?} <- seeds {:?}",
+                origins.ranges()
+            )?;
+        }
+        _ => bail!("expected no arguments, a location or a range of locations `start..end`"),
+    }
+    Ok(out)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Seeds, Vec<RangeMap>);
+
+    fn parse(input: &str) -> anyhow::Result<(Seeds, Vec<RangeMap>)> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a((seeds, maps): &Self::Input<'_>) -> anyhow::Result<u64> {
+        let f = seed_to_location(maps);
+        seeds
+            .iter()
+            .map(|&seed| f.apply(seed))
+            .min()
+            .context("no seeds")
+    }
+
+    fn part_b((seeds, maps): &Self::Input<'_>) -> anyhow::Result<u64> {
+        seed_to_location(maps)
+            .image(&seed_ranges(seeds)?)
+            .min()
+            .context("no seeds")
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let (seeds, maps) = Day5::parse(include_str!("../../in/5/ex")).unwrap();
+    let f = seed_to_location(&maps);
+
+    for seed in 0..200 {
+        let step_by_step = maps
+            .iter()
+            .fold(seed, |x, map| PiecewiseMap::from_ranges(map).apply(x));
+        assert_eq!(f.apply(seed), step_by_step);
+    }
+
+    // The seeds landing on each answer include one of the seeds asked about.
+    let a = Day5::part_a(&(seeds.clone(), maps.clone())).unwrap();
+    let b = Day5::part_b(&(seeds.clone(), maps.clone())).unwrap();
+    let ranges = seed_ranges(&seeds).unwrap();
+    let at = |loc: u64| f.inverse_image(&IntervalSet::from(loc..loc + 1));
+    assert!(seeds.iter().any(|&seed| at(a).contains(seed)));
+    assert!(!at(b).intersection(&ranges).is_empty());
+    assert_eq!(at(b - 1).intersection(&ranges).min(), None);
+    assert_eq!((a, b), (35, 46));
+
+    assert!(Day5::parse("seeds: 1\n\nseed-to-soil map:\n50 98 2\ngarbage\n").is_err());
+
+    let no_seeds = Day5::parse("seeds: \n\nseed-to-soil map:\n50 98 2\n").unwrap();
+    assert!(Day5::part_a(&no_seeds).is_err());
+    assert!(Day5::part_b(&no_seeds).is_err());
+    let odd = Day5::parse("seeds: 5\n").unwrap();
+    assert_eq!(Day5::part_a(&odd).unwrap(), 5);
+    assert!(Day5::part_b(&odd).is_err());
+}
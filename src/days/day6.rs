@@ -0,0 +1,128 @@
+use chumsky::prelude::*;
+use num::{BigUint, Integer, Zero};
+
+use crate::{chumsky_err, chumsky_parse, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Race {
+    time: BigUint,
+    record: BigUint,
+}
+
+fn parser() -> impl Parser<char, Vec<Race>, Error = Simple<char>> {
+    let int = text::int(10)
+        .from_str::<BigUint>()
+        .try_map(chumsky_err)
+        .padded();
+
+    let times = just("Time:").ignore_then(int.repeated().at_least(1));
+    let records = just("Distance:").ignore_then(int.repeated().at_least(1));
+
+    times
+        .then(records)
+        .then_ignore(end())
+        .try_map(|(ts, rs): (Vec<_>, Vec<_>), span| {
+            if ts.len() != rs.len() {
+                return Err(Simple::custom(
+                    span,
+                    format!("{} times but {} distances", ts.len(), rs.len()),
+                ));
+            }
+            Ok(ts
+                .into_iter()
+                .zip(rs)
+                .map(|(time, record)| Race { time, record })
+                .collect())
+        })
+}
+
+/// Counts the hold times `h` that beat the record, i.e. with `h * (time - h) > record`.
+///
+/// Writing `k = |2h - time|`, that's `k² < time² - 4 * record`, so the answer follows from the
+/// largest such `k` with the same parity as `time`: each `k > 0` gives two hold times, and `k = 0`
+/// gives one.
+fn ways(race: &Race) -> BigUint {
+    let Race { time, record } = race;
+
+    let square = time * time;
+    let four_record = record * 4u32;
+    if square <= four_record {
+        return BigUint::zero();
+    }
+    let disc = square - four_record;
+
+    let mut k = disc.sqrt();
+    if &k * &k == disc {
+        k -= 1u32;
+    }
+    if k.is_odd() != time.is_odd() {
+        if k.is_zero() {
+            return BigUint::zero();
+        }
+        k -= 1u32;
+    }
+
+    k + 1u32
+}
+
+/// Joins the digits of the numbers, as if the spaces between them weren't there.
+fn concat(a: &BigUint, b: &BigUint) -> BigUint {
+    a * BigUint::from(10u32).pow(b.to_string().len() as u32) + b
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Vec<Race>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Race>> {
+        Ok(chumsky_parse(parser(), input)?)
+    }
+
+    fn part_a(races: &Self::Input<'_>) -> BigUint {
+        races.iter().map(ways).product()
+    }
+
+    fn part_b(races: &Self::Input<'_>) -> BigUint {
+        let race = races
+            .iter()
+            .cloned()
+            .reduce(|a, b| Race {
+                time: concat(&a.time, &b.time),
+                record: concat(&a.record, &b.record),
+            })
+            .expect("the parser requires at least one race");
+
+        ways(&race)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let race = |time: u64, record: u64| Race {
+        time: time.into(),
+        record: record.into(),
+    };
+    let brute = |time: u64, record: u64| (0..=time).filter(|h| h * (time - h) > record).count();
+
+    for time in 0..40 {
+        for record in 0..time * time / 4 + 2 {
+            assert_eq!(
+                ways(&race(time, record)),
+                brute(time, record).into(),
+                "time {time}, record {record}"
+            );
+        }
+    }
+
+    let huge = Race {
+        time: "1000000000000000000000000".parse().unwrap(),
+        record: "1".parse().unwrap(),
+    };
+    assert_eq!(ways(&huge).to_string(), "999999999999999999999999");
+
+    assert!(Day6::parse("Time: 7 15\nDistance: 9\n").is_err());
+    assert!(Day6::parse("Time:\nDistance:\n").is_err());
+    assert_eq!(Day6::parse("Time: 7\nDistance: 9\n").unwrap().len(), 1);
+}
@@ -0,0 +1,139 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::{chumsky_err, chumsky_parse, Solution};
+
+pub type Hand = Vec<char>;
+pub type Bid = u16;
+
+/// How hands of the same type are ordered.
+#[derive(Debug, Clone, Copy)]
+pub enum TieBreak {
+    /// Card by card, in the order they were dealt.
+    InOrder,
+    /// Card by card, strongest first.
+    Strongest,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    /// Every card, weakest first.
+    pub order: &'static str,
+    /// Cards that count as whichever card makes the hand strongest.
+    pub wildcards: &'static str,
+    pub hand_size: usize,
+    pub tie_break: TieBreak,
+}
+
+pub const STANDARD: Rules = Rules {
+    order: "23456789TJQKA",
+    wildcards: "",
+    hand_size: 5,
+    tie_break: TieBreak::InOrder,
+};
+
+pub const JOKERS: Rules = Rules {
+    order: "J23456789TQKA",
+    wildcards: "J",
+    ..STANDARD
+};
+
+impl Rules {
+    /// The sizes of the groups of equal cards, largest first, which orders hands by type when
+    /// compared lexicographically. The wildcards are best spent joining the largest group.
+    fn shape(&self, hand: &[char]) -> Vec<usize> {
+        let (wild, natural): (Vec<char>, Vec<char>) =
+            hand.iter().partition(|&&c| self.wildcards.contains(c));
+
+        let mut groups = natural
+            .into_iter()
+            .counts()
+            .into_values()
+            .sorted_unstable_by(|a, b| b.cmp(a))
+            .collect_vec();
+        match groups.first_mut() {
+            Some(largest) => *largest += wild.len(),
+            None => groups.push(wild.len()),
+        }
+        groups
+    }
+
+    fn key(&self, hand: &[char]) -> (Vec<usize>, Vec<usize>) {
+        assert_eq!(hand.len(), self.hand_size, "wrong number of cards");
+
+        let mut cards = hand
+            .iter()
+            .map(|&c| self.order.find(c).expect("card not in deck"))
+            .collect_vec();
+        if let TieBreak::Strongest = self.tie_break {
+            cards.sort_unstable_by(|a, b| b.cmp(a));
+        }
+        (self.shape(hand), cards)
+    }
+
+    pub fn winnings(&self, hands: &[(Hand, Bid)]) -> u64 {
+        hands
+            .iter()
+            .sorted_by_cached_key(|(hand, _)| self.key(hand))
+            .enumerate()
+            .map(|(i, &(_, bid))| (i + 1) as u64 * bid as u64)
+            .sum()
+    }
+}
+
+/// Both rule sets deal from the same deck, so either one can be used to parse.
+fn parser(rules: Rules) -> impl Parser<char, Vec<(Hand, Bid)>, Error = Simple<char>> {
+    let hand = one_of(rules.order)
+        .repeated()
+        .exactly(rules.hand_size)
+        .padded();
+
+    let bid = text::int(10)
+        .from_str::<Bid>()
+        .try_map(chumsky_err)
+        .padded();
+    let row = hand.then(bid);
+
+    row.repeated().then_ignore(end())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<(Hand, Bid)>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<(Hand, Bid)>> {
+        Ok(chumsky_parse(parser(STANDARD), input)?)
+    }
+
+    fn part_a(hands: &Self::Input<'_>) -> u64 {
+        STANDARD.winnings(hands)
+    }
+
+    fn part_b(hands: &Self::Input<'_>) -> u64 {
+        JOKERS.winnings(hands)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day7::parse(include_str!("../../in/7/ex")).unwrap();
+    assert_eq!(Day7::part_a(&ex), 6440);
+    assert_eq!(Day7::part_b(&ex), 5905);
+
+    let shape = |rules: Rules, hand: &str| rules.shape(&hand.chars().collect_vec());
+    assert_eq!(shape(STANDARD, "KTJJT"), [2, 2, 1]);
+    assert_eq!(shape(JOKERS, "KTJJT"), [4, 1]);
+    assert_eq!(shape(JOKERS, "JJJJJ"), [5]);
+
+    let poker = Rules {
+        tie_break: TieBreak::Strongest,
+        ..STANDARD
+    };
+    let cards = |hand: &str| hand.chars().collect_vec();
+    assert!(poker.key(&cards("2AKQT")) > poker.key(&cards("KQJT9")));
+    assert!(STANDARD.key(&cards("2AKQT")) < STANDARD.key(&cards("KQJT9")));
+
+    assert!(Day7::parse("32T3K 765\nT55J5 684\nZZZZZ 28\n").is_err());
+}
@@ -0,0 +1,210 @@
+use std::iter::{self, repeat};
+
+use anyhow::{ensure, Context};
+use chumsky::prelude::*;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    chumsky_parse,
+    crt::{self, Congruence, CrtError},
+    cycle::{self, Cycle},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Dir {
+    Left,
+    Right,
+}
+
+type Network = (Vec<Dir>, Vec<(String, (String, String))>);
+
+fn parser() -> impl Parser<char, Network, Error = Simple<char>> {
+    let directions = choice((just('L').to(Dir::Left), just('R').to(Dir::Right)))
+        .repeated()
+        .at_least(1)
+        .collect()
+        .padded();
+
+    let tag = filter(|c: &char| c.is_ascii_uppercase() || c.is_ascii_digit())
+        .repeated()
+        .collect();
+
+    let node = tag
+        .then_ignore(just(" = ("))
+        .then(tag.then_ignore(just(", ")).then(tag).then_ignore(just(")")))
+        .padded();
+
+    directions.then(node.repeated()).then_ignore(end())
+}
+
+/// The steps at which a ghost stands on a Z node.
+struct Ghost {
+    cycle: Cycle,
+    /// Every such step before the ghost's path first repeats, i.e. below `start + period`.
+    hits: Vec<usize>,
+}
+
+impl Ghost {
+    fn hits(&self, step: u64) -> bool {
+        self.hits.contains(&self.cycle.reduce(step as usize))
+    }
+}
+
+/// The first step at which every ghost stands on a Z node at once, if there is one.
+fn earliest_common_hit(ghosts: &[Ghost]) -> Result<Option<u64>, CrtError> {
+    // A hit before a ghost's cycle starts happens only once, so try each of those directly.
+    // They all come before any step where every ghost is in its cycle.
+    let early = ghosts
+        .iter()
+        .flat_map(|ghost| ghost.hits.iter().filter(|&&hit| hit < ghost.cycle.start))
+        .map(|&hit| hit as u64)
+        .filter(|&step| ghosts.iter().all(|ghost| ghost.hits(step)))
+        .min();
+    if early.is_some() {
+        return Ok(early);
+    }
+
+    // Every hit inside a cycle repeats with its period. Pick one of them for every ghost and find
+    // the steps at which all the picks line up.
+    let Some(all_cycling) = ghosts.iter().map(|ghost| ghost.cycle.start).max() else {
+        return Ok(None);
+    };
+    let picks = ghosts
+        .iter()
+        .map(|ghost| {
+            ghost
+                .hits
+                .iter()
+                .filter(|&&hit| hit >= ghost.cycle.start)
+                .map(|&hit| Congruence::new(hit as u64, ghost.cycle.period as u64))
+        })
+        .multi_cartesian_product();
+
+    // A pick whose steps all lie past `u64::MAX` can't be the earliest if any other one fits.
+    let mut earliest: Option<u64> = None;
+    let mut overflowed = false;
+    for picks in picks {
+        match crt::solve(picks).and_then(|s| s.first_at_least(all_cycling as u64)) {
+            Ok(step) => earliest = Some(earliest.map_or(step, |earliest| earliest.min(step))),
+            Err(CrtError::NoSolution) => {}
+            Err(CrtError::Overflow) => overflowed = true,
+        }
+    }
+    match earliest {
+        None if overflowed => Err(CrtError::Overflow),
+        earliest => Ok(earliest),
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Network;
+
+    fn parse(input: &str) -> anyhow::Result<Network> {
+        let (directions, nodes) = chumsky_parse(parser(), input)?;
+        let tags: FxHashSet<_> = nodes.iter().map(|(tag, _)| tag).collect();
+        for (tag, (left, right)) in &nodes {
+            for next in [left, right] {
+                ensure!(
+                    tags.contains(next),
+                    "node {tag} leads to unknown node {next}"
+                );
+            }
+        }
+        Ok((directions, nodes))
+    }
+
+    fn part_a((directions, nodes): &Self::Input<'_>) -> anyhow::Result<u64> {
+        let tag_to_index: FxHashMap<_, _> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, (tag, _))| (&**tag, i))
+            .collect();
+
+        let mut pos = "AAA";
+        let goal = "ZZZ";
+        ensure!(tag_to_index.contains_key(pos), "no node {pos}");
+
+        // Past this many steps, some position in the directions repeats on the same node.
+        let max_steps = directions.len() * nodes.len();
+        let mut directions = repeat(()).flat_map(|_| directions.iter().copied());
+        let mut steps = 0;
+
+        while pos != goal {
+            ensure!(steps < max_steps, "{goal} can't be reached from AAA");
+            let i = tag_to_index[pos];
+            pos = match directions.next().unwrap() {
+                Dir::Left => &*nodes[i].1 .0,
+                Dir::Right => &*nodes[i].1 .1,
+            };
+            steps += 1;
+        }
+
+        Ok(steps as u64)
+    }
+
+    fn part_b((dirs, nodes): &Self::Input<'_>) -> anyhow::Result<u64> {
+        let tag_to_index: FxHashMap<_, _> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, (tag, _))| (&**tag, i))
+            .collect();
+
+        let start_nodes = nodes.iter().filter_map(|(tag, _)| {
+            if tag.ends_with('A') {
+                Some(&**tag)
+            } else {
+                None
+            }
+        });
+
+        let step = |&(j, pos): &(usize, &str)| {
+            let i = tag_to_index[pos];
+            let pos = match dirs[j] {
+                Dir::Left => &*nodes[i].1 .0,
+                Dir::Right => &*nodes[i].1 .1,
+            };
+            ((j + 1) % dirs.len(), pos)
+        };
+
+        let ghosts = start_nodes
+            .map(|start| {
+                let cycle = cycle::hashed((0, start), step);
+                let hits = iter::successors(Some((0, start)), |state| Some(step(state)))
+                    .take(cycle.start + cycle.period)
+                    .positions(|(_, pos)| pos.ends_with('Z'))
+                    .collect();
+                Ghost { cycle, hits }
+            })
+            .collect_vec();
+
+        earliest_common_hit(&ghosts)?.context("no common step")
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day8::parse(include_str!("../../in/8/ex")).unwrap();
+    assert_eq!(Day8::part_a(&ex).unwrap(), 2);
+    let ex3 = Day8::parse(include_str!("../../in/8/ex3")).unwrap();
+    assert!(Day8::part_a(&ex3).is_err());
+    let stuck = Day8::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert!(Day8::part_a(&stuck).is_err());
+    assert!(Day8::parse("L\n\nAAA = (BBB, BBB)\n").is_err());
+    assert!(Day8::parse("\nAAA = (AAA, AAA)\n").is_err());
+
+    let ex4 = Day8::parse(include_str!("../../in/8/ex4")).unwrap();
+    assert_eq!(Day8::part_b(&ex4).unwrap(), 10);
+
+    let never = "L\n\nAAA = (AAZ, AAZ)\nAAZ = (AAA, AAA)\n\
+                 BBA = (BBB, BBB)\nBBB = (BBZ, BBZ)\nBBZ = (BBB, BBB)\n";
+    let never = Day8::parse(never).unwrap();
+    assert_eq!(
+        Day8::part_b(&never).unwrap_err().to_string(),
+        "no common step"
+    );
+}
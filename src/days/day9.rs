@@ -0,0 +1,68 @@
+use std::ops::Range;
+
+use anyhow::anyhow;
+use chumsky::prelude::*;
+use num::BigInt;
+
+use crate::{chumsky_err, chumsky_parse, poly::Polynomial, Solution};
+
+fn parser() -> impl Parser<char, Vec<(Vec<i64>, Range<usize>)>, Error = Simple<char>> {
+    let int = just('-')
+        .or_not()
+        .chain::<char, _, _>(filter(|c: &char| c.is_ascii_digit()).repeated().at_least(1))
+        .collect::<String>()
+        .from_str::<i64>()
+        .try_map(chumsky_err)
+        .padded_by(just(' ').ignored().repeated());
+    let seq = int
+        .repeated()
+        .at_least(1)
+        .map_with_span(|seq, span| (seq, span))
+        .padded();
+    seq.repeated().then_ignore(end())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Polynomial>;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Polynomial>> {
+        let seqs = chumsky_parse(parser(), input)?;
+        seqs.iter()
+            .map(|(seq, span)| {
+                Polynomial::fit(seq).map_err(|err| {
+                    let line = input
+                        .chars()
+                        .take(span.start)
+                        .filter(|&c| c == '\n')
+                        .count()
+                        + 1;
+                    anyhow!("line {line}: {err}")
+                })
+            })
+            .collect()
+    }
+
+    fn part_a(polys: &Self::Input<'_>) -> BigInt {
+        polys.iter().map(|poly| poly.ahead(1)).sum()
+    }
+
+    fn part_b(polys: &Self::Input<'_>) -> BigInt {
+        polys.iter().map(|poly| poly.behind(1)).sum()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let ex = Day9::parse(include_str!("../../in/9/ex")).unwrap();
+    assert_eq!(Day9::part_a(&ex), 114.into());
+    assert_eq!(Day9::part_b(&ex), 2.into());
+
+    let err = Day9::parse("0 1 2\n\n1 2 4\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3: the differences of 3 samples never reach an all-zero row"
+    );
+}
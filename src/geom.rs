@@ -0,0 +1,226 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use strum::{Display, EnumIter};
+
+/// A cell of a grid, as `(row, col)` with rows growing downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// The difference between two [`Pos`]itions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Delta {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Debug, Display, EnumIter, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    #[strum(to_string = "^")]
+    Up,
+    #[strum(to_string = ">")]
+    Right,
+    #[strum(to_string = "v")]
+    Down,
+    #[strum(to_string = "<")]
+    Left,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// Returns `None` if the result would be negative.
+    pub fn offset(self, delta: Delta) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(delta.row)?,
+            col: self.col.checked_add_signed(delta.col)?,
+        })
+    }
+
+    /// Returns `None` if the result would be outside a grid of the given `(rows, cols)`.
+    pub fn bounded_offset(self, delta: Delta, shape: (usize, usize)) -> Option<Pos> {
+        self.offset(delta)
+            .filter(|pos| pos.row < shape.0 && pos.col < shape.1)
+    }
+
+    /// Wraps around the edges of a grid of the given `(rows, cols)`.
+    pub fn wrapping_offset(self, delta: Delta, shape: (usize, usize)) -> Pos {
+        let wrap = |x: usize, d: isize, n: usize| (x as isize + d).rem_euclid(n as isize) as usize;
+        Pos {
+            row: wrap(self.row, delta.row, shape.0),
+            col: wrap(self.col, delta.col, shape.1),
+        }
+    }
+
+    /// The neighbour in direction `dir`, if it's inside a grid of the given `(rows, cols)`.
+    pub fn step(self, dir: Dir, shape: (usize, usize)) -> Option<Pos> {
+        self.bounded_offset(dir.delta(), shape)
+    }
+
+    /// The orthogonal neighbours inside a grid of the given `(rows, cols)`.
+    pub fn neighbors4(self, shape: (usize, usize)) -> impl Iterator<Item = Pos> {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(dir, shape))
+    }
+
+    /// The orthogonal and diagonal neighbours inside a grid of the given `(rows, cols)`.
+    pub fn neighbors8(self, shape: (usize, usize)) -> impl Iterator<Item = Pos> {
+        Delta::NEIGHBORS8
+            .into_iter()
+            .filter_map(move |delta| self.bounded_offset(delta, shape))
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Delta {
+    /// The offsets to the eight surrounding cells, clockwise from the top left.
+    pub const NEIGHBORS8: [Delta; 8] = [
+        Delta::new(-1, -1),
+        Delta::new(-1, 0),
+        Delta::new(-1, 1),
+        Delta::new(0, 1),
+        Delta::new(1, 1),
+        Delta::new(1, 0),
+        Delta::new(1, -1),
+        Delta::new(0, -1),
+    ];
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Delta { row, col }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub const fn delta(self) -> Delta {
+        match self {
+            Dir::Up => Delta::new(-1, 0),
+            Dir::Right => Delta::new(0, 1),
+            Dir::Down => Delta::new(1, 0),
+            Dir::Left => Delta::new(0, -1),
+        }
+    }
+
+    pub fn reverse(self) -> Dir {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Dir {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir::Left | Dir::Right)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos { row, col }
+    }
+}
+
+impl From<Pos> for (usize, usize) {
+    fn from(pos: Pos) -> Self {
+        (pos.row, pos.col)
+    }
+}
+
+impl From<Dir> for Delta {
+    fn from(dir: Dir) -> Self {
+        dir.delta()
+    }
+}
+
+impl Sub for Pos {
+    type Output = Delta;
+
+    fn sub(self, other: Pos) -> Delta {
+        Delta::new(
+            self.row as isize - other.row as isize,
+            self.col as isize - other.col as isize,
+        )
+    }
+}
+
+impl Add for Delta {
+    type Output = Delta;
+
+    fn add(self, other: Delta) -> Delta {
+        Delta::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Delta {
+    fn add_assign(&mut self, other: Delta) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Delta {
+    type Output = Delta;
+
+    fn sub(self, other: Delta) -> Delta {
+        self + -other
+    }
+}
+
+impl Neg for Delta {
+    type Output = Delta;
+
+    fn neg(self) -> Delta {
+        Delta::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Delta {
+    type Output = Delta;
+
+    fn mul(self, n: isize) -> Delta {
+        Delta::new(self.row * n, self.col * n)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use itertools::Itertools;
+
+    for dir in Dir::ALL {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+        assert_eq!(dir.reverse().delta(), -dir.delta());
+    }
+    assert_eq!(Dir::Up.turn_right(), Dir::Right);
+
+    let pos = Pos::new(0, 1);
+    assert_eq!(
+        pos.neighbors4((2, 3)).collect_vec(),
+        [Pos::new(0, 2), Pos::new(1, 1), Pos::new(0, 0)]
+    );
+    assert_eq!(pos.neighbors8((2, 3)).count(), 5);
+    assert_eq!(
+        pos.wrapping_offset(Dir::Up.delta() * 3, (2, 3)),
+        Pos::new(1, 1)
+    );
+    assert_eq!(pos.manhattan(Pos::new(3, 0)), 4);
+    assert_eq!(Pos::new(3, 0) - pos, Delta::new(3, -1));
+}
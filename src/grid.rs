@@ -0,0 +1,430 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use itertools::Itertools;
+use thiserror::Error;
+use winnow::{
+    ascii::line_ending,
+    combinator::{repeat, repeat_till0},
+    error::ContextError,
+    prelude::*,
+};
+
+use crate::Pos;
+
+/// A rectangular grid stored row by row, indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    nrows: usize,
+    ncols: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Error, Debug)]
+#[error("row {row} has {len} columns, expected {expected}")]
+pub struct RaggedRows {
+    pub row: usize,
+    pub len: usize,
+    pub expected: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(nrows: usize, ncols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(nrows, ncols, vec![value; nrows * ncols])
+    }
+
+    /// Panics unless `cells` holds exactly `nrows * ncols` cells.
+    pub fn from_vec(nrows: usize, ncols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), nrows * ncols, "wrong number of cells");
+        Grid {
+            nrows,
+            ncols,
+            cells,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedRows> {
+        let ncols = rows.first().map_or(0, Vec::len);
+        if let Some((row, v)) = rows.iter().find_position(|v| v.len() != ncols) {
+            return Err(RaggedRows {
+                row,
+                len: v.len(),
+                expected: ncols,
+            });
+        }
+
+        let nrows = rows.len();
+        Ok(Self::from_vec(
+            nrows,
+            ncols,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&T> {
+        (row < self.nrows && col < self.ncols).then(|| &self.cells[row * self.ncols + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut T> {
+        (row < self.nrows && col < self.ncols).then(|| &mut self.cells[row * self.ncols + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.ncols..(row + 1) * self.ncols]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.ncols, "column {col} out of bounds");
+        self.cells[col..].iter().step_by(self.ncols)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks_exact` panics on a chunk size of 0.
+        self.cells.chunks_exact(self.ncols.max(1))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> {
+        (0..self.ncols).map(|col| self.column(col))
+    }
+
+    /// All cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// All cells in row-major order, with their positions.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let ncols = self.ncols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / ncols, i % ncols), cell))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        self.map_indexed(|_, cell| f(cell))
+    }
+
+    pub fn map_indexed<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid::from_vec(
+            self.nrows,
+            self.ncols,
+            self.indexed_iter()
+                .map(|(pos, cell)| f(pos, cell))
+                .collect(),
+        )
+    }
+
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            cells: &self.cells,
+            layout: Layout::new(self.nrows, self.ncols),
+        }
+    }
+
+    pub fn view_mut(&mut self) -> GridViewMut<'_, T> {
+        GridViewMut {
+            layout: Layout::new(self.nrows, self.ncols),
+            cells: &mut self.cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for shape {:?}", self.shape()))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let shape = self.shape();
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for shape {shape:?}"))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self[(pos.row, pos.col)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        &mut self[(pos.row, pos.col)]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.view().fmt(f)
+    }
+}
+
+/// Where each `(row, col)` of a view lives in the grid's cells.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    nrows: usize,
+    ncols: usize,
+    origin: usize,
+    row_stride: isize,
+    col_stride: isize,
+}
+
+impl Layout {
+    fn new(nrows: usize, ncols: usize) -> Self {
+        Layout {
+            nrows,
+            ncols,
+            origin: 0,
+            row_stride: ncols as isize,
+            col_stride: 1,
+        }
+    }
+
+    fn offset(&self, (row, col): (usize, usize)) -> Option<usize> {
+        (row < self.nrows && col < self.ncols).then(|| {
+            (self.origin as isize + row as isize * self.row_stride + col as isize * self.col_stride)
+                as usize
+        })
+    }
+
+    fn transpose(self) -> Self {
+        Layout {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            origin: self.origin,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    fn flip_rows(self) -> Self {
+        Layout {
+            origin: self
+                .offset((self.nrows.saturating_sub(1), 0))
+                .unwrap_or(self.origin),
+            row_stride: -self.row_stride,
+            ..self
+        }
+    }
+
+    fn flip_cols(self) -> Self {
+        Layout {
+            origin: self
+                .offset((0, self.ncols.saturating_sub(1)))
+                .unwrap_or(self.origin),
+            col_stride: -self.col_stride,
+            ..self
+        }
+    }
+}
+
+macro_rules! view_methods {
+    () => {
+        pub fn nrows(&self) -> usize {
+            self.layout.nrows
+        }
+
+        pub fn ncols(&self) -> usize {
+            self.layout.ncols
+        }
+
+        pub fn shape(&self) -> (usize, usize) {
+            (self.layout.nrows, self.layout.ncols)
+        }
+
+        pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+            self.layout.offset(pos).map(|i| &self.cells[i])
+        }
+
+        /// Swaps rows and columns.
+        pub fn transpose(mut self) -> Self {
+            self.layout = self.layout.transpose();
+            self
+        }
+
+        /// Reverses the order of the rows, mirroring the view top to bottom.
+        pub fn flip_rows(mut self) -> Self {
+            self.layout = self.layout.flip_rows();
+            self
+        }
+
+        /// Reverses the order of the columns, mirroring the view left to right.
+        pub fn flip_cols(mut self) -> Self {
+            self.layout = self.layout.flip_cols();
+            self
+        }
+
+        /// Rotates the view a quarter turn clockwise.
+        pub fn rot90(self) -> Self {
+            self.transpose().flip_cols()
+        }
+
+        pub fn to_grid(&self) -> Grid<T>
+        where
+            T: Clone,
+        {
+            let (nrows, ncols) = self.shape();
+            let cells = (0..nrows)
+                .cartesian_product(0..ncols)
+                .map(|pos| self[pos].clone())
+                .collect();
+            Grid::from_vec(nrows, ncols, cells)
+        }
+    };
+}
+
+/// A borrowed, possibly transposed, flipped or rotated, view of a [`Grid`].
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    cells: &'a [T],
+    layout: Layout,
+}
+
+impl<T> GridView<'_, T> {
+    view_methods!();
+}
+
+/// Like [`GridView`], but allows writing through to the grid.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    cells: &'a mut [T],
+    layout: Layout,
+}
+
+impl<T> GridViewMut<'_, T> {
+    view_methods!();
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.layout.offset(pos).map(|i| &mut self.cells[i])
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for shape {:?}", self.shape()))
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for shape {:?}", self.shape()))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let shape = self.shape();
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for shape {shape:?}"))
+    }
+}
+
+/// Writes one line per row, each cell through its own `Display`.
+impl<T: fmt::Display> fmt::Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.nrows() {
+            for col in 0..self.ncols() {
+                write!(f, "{}", self[(row, col)])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses lines of `element`s into a grid. Every line must end in a line ending and have the same
+/// number of elements as the first.
+pub fn grid<'a, T>(
+    mut element: impl Parser<&'a str, T, ContextError>,
+) -> impl Parser<&'a str, Grid<T>, ContextError> {
+    move |input: &mut &'a str| {
+        let mut cells: Vec<_> = repeat_till0(element.by_ref(), line_ending)
+            .parse_next(input)?
+            .0;
+        let ncols = cells.len();
+
+        let rows: Vec<Vec<_>> = repeat(
+            ..,
+            (repeat(ncols, element.by_ref()), line_ending).map(|(v, _)| v),
+        )
+        .parse_next(input)?;
+
+        let nrows = rows.len() + 1;
+        cells.extend(rows.into_iter().flatten());
+
+        Ok(Grid::from_vec(nrows, ncols, cells))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let grid = grid(winnow::token::any).parse("abc\ndef\n").unwrap();
+    assert_eq!(grid.shape(), (2, 3));
+    assert_eq!(grid[(1, 0)], 'd');
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    assert_eq!(grid.view().transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.view().rot90().to_string(), "da\neb\nfc\n");
+    assert_eq!(
+        grid.view().rot90().rot90().to_grid().to_string(),
+        "fed\ncba\n"
+    );
+    assert_eq!(grid.view().flip_rows().to_string(), "def\nabc\n");
+
+    let mut copy = grid.clone();
+    copy.view_mut().rot90()[(0, 0)] = 'x';
+    assert_eq!(copy.to_string(), "abc\nxef\n");
+
+    assert_eq!(
+        Grid::from_rows(vec![vec![1, 2], vec![3]])
+            .unwrap_err()
+            .to_string(),
+        "row 1 has 1 columns, expected 2"
+    );
+}
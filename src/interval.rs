@@ -0,0 +1,160 @@
+use std::{
+    cmp::{max, min},
+    iter::Sum,
+    ops::{Range, Sub},
+};
+
+/// A set of values stored as sorted, disjoint half-open ranges, with no two ranges touching.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// The number of values in the set.
+    pub fn size(&self) -> T
+    where
+        T: Sub<Output = T> + Sum,
+    {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&Self::from(range));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let range = max(a.start, b.start)..min(a.end, b.end);
+            if !range.is_empty() {
+                ranges.push(range);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for a in &self.ranges {
+            let mut start = a.start;
+            while let Some(b) = other.ranges.get(j) {
+                if b.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if b.start >= a.end {
+                    break;
+                }
+                if start < b.start {
+                    ranges.push(start..b.start);
+                }
+                start = b.end;
+                if b.end > a.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < a.end {
+                ranges.push(start..a.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Splits the set into the values below `at` and the values from `at` upwards.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for r in &self.ranges {
+            if r.end <= at {
+                below.push(r.clone());
+            } else if r.start >= at {
+                above.push(r.clone());
+            } else {
+                below.push(r.start..at);
+                above.push(at..r.end);
+            }
+        }
+        (IntervalSet { ranges: below }, IntervalSet { ranges: above })
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        sorted.sort_unstable_by_key(|r| r.start);
+
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(sorted.len());
+        for r in sorted {
+            match ranges.last_mut() {
+                Some(last) if r.start <= last.end => last.end = max(last.end, r.end),
+                _ => ranges.push(r),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let a: IntervalSet<u32> = [0..5, 10..20, 4..7, 20..22].into_iter().collect();
+    assert_eq!(a.ranges(), [0..7, 10..22]);
+    let b = IntervalSet::from(5..12);
+
+    assert_eq!(a.union(&b), IntervalSet::from(0..22));
+    assert_eq!(a.intersection(&b).ranges(), [5..7, 10..12]);
+    assert_eq!(a.difference(&b).ranges(), [0..5, 12..22]);
+    assert_eq!(b.difference(&a), IntervalSet::from(7..10));
+    assert_eq!(a.size(), 19);
+    assert!(a.contains(6) && !a.contains(7) && a.contains(21) && !a.contains(22));
+
+    let (below, above) = a.split_at(15);
+    assert_eq!(below.ranges(), [0..7, 10..15]);
+    assert_eq!(above, IntervalSet::from(15..22));
+}
@@ -1,10 +1,161 @@
-use std::{fmt, io::Read, ops::Range};
-
+#![feature(map_try_insert)]
+// Days implement `Solution` with their concrete answer types, so tests can compare them directly.
+#![allow(refining_impl_trait)]
+
+use std::{
+    any::Any,
+    fmt,
+    hint::black_box,
+    io::Read,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
 use chumsky::error::Simple;
-use itertools::Itertools;
-use ndarray::{Array2, ShapeError};
-use num::traits::AsPrimitive;
-use thiserror::Error;
+use num::{traits::AsPrimitive, BigInt, BigUint};
+use strum::{Display, EnumString};
+
+pub mod crt;
+pub mod cycle;
+pub mod days;
+mod geom;
+mod grid;
+mod interval;
+mod parse_error;
+pub mod poly;
+pub mod render;
+pub mod search;
+
+pub use geom::{Delta, Dir, Pos};
+pub use grid::{grid, Grid, GridView, GridViewMut, RaggedRows};
+pub use interval::IntervalSet;
+pub use parse_error::ParseError;
+
+#[derive(Debug, Display, EnumString, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[strum(serialize_all = "lowercase")]
+pub enum Part {
+    A,
+    B,
+}
+
+pub trait Solution {
+    type Input<'a>;
+
+    /// The parts that have a solution. Days 24 and 25 only solve part a, so they override this.
+    const PARTS: &'static [Part] = &[Part::A, Part::B];
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>>;
+
+    fn part_a(input: &Self::Input<'_>) -> impl Answer + use<Self>;
+
+    fn part_b(_input: &Self::Input<'_>) -> impl Answer + use<Self> {
+        "unsolved"
+    }
+}
+
+/// What a part returns: a plain answer, or a `Result` for parts that can fail on bad input.
+pub trait Answer {
+    fn into_answer(self) -> anyhow::Result<String>;
+}
+
+macro_rules! display_answer {
+    ($($ty:ty),*) => {$(
+        impl Answer for $ty {
+            fn into_answer(self) -> anyhow::Result<String> {
+                Ok(self.to_string())
+            }
+        }
+    )*};
+}
+
+display_answer!(u32, u64, u128, usize, i64, BigInt, BigUint, String, &str);
+
+impl<T: Answer, E: Into<anyhow::Error>> Answer for Result<T, E> {
+    fn into_answer(self) -> anyhow::Result<String> {
+        self.map_err(Into::into)?.into_answer()
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input types can share a registry.
+pub trait DynSolution: Sync {
+    fn parts(&self) -> &'static [Part];
+
+    /// Parses the input and solves each of the parts, which can fail separately.
+    fn run(
+        &self,
+        input: &str,
+        parts: &[Part],
+    ) -> anyhow::Result<Vec<(Part, anyhow::Result<String>)>>;
+
+    /// Like [`run`](DynSolution::run), but measures each stage instead of returning the answers.
+    fn time(&self, input: &str, parts: &[Part]) -> anyhow::Result<Timings>;
+}
+
+/// Wall time of one parse and of each part solved from its result.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn run(
+        &self,
+        input: &str,
+        parts: &[Part],
+    ) -> anyhow::Result<Vec<(Part, anyhow::Result<String>)>> {
+        let input = S::parse(input)?;
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                // A part that panics shouldn't take the other part's answer down with it.
+                let answer = panic::catch_unwind(AssertUnwindSafe(|| match part {
+                    Part::A => S::part_a(&input).into_answer(),
+                    Part::B => S::part_b(&input).into_answer(),
+                }))
+                .unwrap_or_else(|payload| Err(anyhow!("panicked: {}", panic_message(&*payload))));
+                (part, answer)
+            })
+            .collect())
+    }
+
+    fn time(&self, input: &str, parts: &[Part]) -> anyhow::Result<Timings> {
+        let start = Instant::now();
+        let input = black_box(S::parse(black_box(input))?);
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                match part {
+                    Part::A => drop(black_box(S::part_a(&input))),
+                    Part::B => drop(black_box(S::part_b(&input))),
+                }
+                (part, start.elapsed())
+            })
+            .collect();
+
+        Ok(Timings { parse, parts })
+    }
+}
+
+/// The message a panic was raised with, if it was given one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg
+    } else {
+        "no message"
+    }
+}
 
 pub fn read_stdin_to_bytes() -> Vec<u8> {
     let mut buf = Vec::new();
@@ -41,25 +192,6 @@ pub fn arr_as<A: AsPrimitive<B>, B: 'static + Copy, const N: usize>(a: [A; N]) -
     a.map(AsPrimitive::as_)
 }
 
-#[derive(Error, Debug)]
-pub enum ToArrayError {
-    #[error("Inconsistent number of columns: {0:?}")]
-    ColumnCount(Option<(usize, usize)>),
-    #[error("{0}")]
-    ShapeError(ShapeError),
-}
-
-pub fn to_array2<T>(vv: Vec<Vec<T>>) -> Result<Array2<T>, ToArrayError> {
-    let width = vv
-        .iter()
-        .map(Vec::len)
-        .all_equal_value()
-        .map_err(ToArrayError::ColumnCount)?;
-    let height = vv.len();
-    Array2::from_shape_vec((height, width), vv.into_iter().flatten().collect_vec())
-        .map_err(ToArrayError::ShapeError)
-}
-
 pub fn chumsky_err<T, E: std::error::Error>(
     res: Result<T, E>,
     span: Range<usize>,
@@ -67,80 +199,26 @@ pub fn chumsky_err<T, E: std::error::Error>(
     res.map_err(|err| Simple::custom(span, err.to_string()))
 }
 
-pub fn parse_stdin<T>(parser: impl chumsky::Parser<char, T, Error = Simple<char>>) -> T {
-    parser.parse(read_stdin_to_string()).unwrap()
+pub fn parse_stdin<T>(
+    parser: impl chumsky::Parser<char, T, Error = Simple<char>>,
+) -> Result<T, ParseError> {
+    chumsky_parse(parser, &read_stdin_to_string())
 }
 
-pub fn offset(pos: (usize, usize), delta: (isize, isize)) -> Option<(usize, usize)> {
-    Some((
-        usize::try_from(pos.0 as isize + delta.0).ok()?,
-        usize::try_from(pos.1 as isize + delta.1).ok()?,
-    ))
+pub fn chumsky_parse<T>(
+    parser: impl chumsky::Parser<char, T, Error = Simple<char>>,
+    input: &str,
+) -> Result<T, ParseError> {
+    parser
+        .parse(input)
+        .map_err(|errs| ParseError::from_chumsky(input, &errs))
 }
 
-pub fn bounded_offset(
-    pos: (usize, usize),
-    delta: (isize, isize),
-    size: (usize, usize),
-) -> Option<(usize, usize)> {
-    offset(pos, delta).and_then(|pos| {
-        if pos.0 < size.0 && pos.1 < size.1 {
-            Some(pos)
-        } else {
-            None
-        }
-    })
-}
-
-pub fn wrapping_offset(
-    pos: (usize, usize),
-    delta: (isize, isize),
-    size: (usize, usize),
-) -> (usize, usize) {
-    (
-        (((pos.0 + size.0) as isize + delta.0) as usize) % size.0,
-        (((pos.1 + size.1) as isize + delta.1) as usize) % size.1,
-    )
-}
-
-pub use winnow_util::*;
-
-mod winnow_util {
-    use itertools::chain;
-    use nalgebra::{DMatrix, Scalar};
-    use winnow::{
-        ascii::line_ending,
-        combinator::{repeat, repeat_till0},
-        error::ContextError,
-        prelude::*,
-    };
-
-    pub fn matrix<'a, T>(
-        mut element: impl Parser<&'a str, T, ContextError>,
-    ) -> impl Parser<&'a str, DMatrix<T>, ContextError>
-    where
-        T: Scalar,
-    {
-        move |input: &mut &'a str| {
-            let first_row: Vec<_> = repeat_till0(element.by_ref(), line_ending)
-                .parse_next(input)?
-                .0;
-            let ncols = first_row.len();
-
-            let rows: Vec<_> = repeat(
-                ..,
-                (repeat(ncols, element.by_ref()), line_ending).map(|(v, _): (Vec<_>, _)| v),
-            )
-            .parse_next(input)?;
-
-            let nrows = rows.len() + 1;
-
-            Ok(DMatrix::from_iterator(
-                nrows,
-                ncols,
-                chain(std::iter::once(first_row), rows).flatten(),
-            )
-            .transpose())
-        }
-    }
+pub fn winnow_parse<'a, T>(
+    mut parser: impl winnow::Parser<&'a str, T, winnow::error::ContextError>,
+    input: &'a str,
+) -> Result<T, ParseError> {
+    parser
+        .parse(input)
+        .map_err(|err| ParseError::from_winnow(input, &err))
 }
@@ -0,0 +1,173 @@
+use std::fmt;
+
+use chumsky::error::{Simple, SimpleReason};
+use itertools::Itertools;
+use winnow::error::{ContextError, StrContext};
+
+/// A parse failure located in the input, from either a chumsky or a winnow parser.
+///
+/// It displays as a message with the line and column, followed by an excerpt of the offending
+/// line with a caret under the failing position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in chars.
+    pub column: usize,
+    /// The line containing the error, without its line ending.
+    pub source_line: String,
+    /// What the parser would have accepted at this position, if it said.
+    pub expected: Vec<String>,
+    /// What was there instead, or `None` at the end of the input.
+    pub found: Option<char>,
+    /// Any further explanation, e.g. from `try_map` or a winnow label.
+    pub message: Option<String>,
+}
+
+impl ParseError {
+    /// Locates the error at byte `offset` in `input`.
+    pub fn new(
+        input: &str,
+        offset: usize,
+        expected: impl IntoIterator<Item = String>,
+        message: Option<String>,
+    ) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            expected: expected.into_iter().sorted().dedup().collect(),
+            found: input[offset..].chars().next(),
+            message,
+        }
+    }
+
+    /// Converts the first of the errors chumsky returned for `input`.
+    pub fn from_chumsky(input: &str, errs: &[Simple<char>]) -> Self {
+        let Some(err) = errs.first() else {
+            return ParseError::new(input, 0, [], Some("unknown error".to_string()));
+        };
+
+        // chumsky counts in chars rather than bytes.
+        let offset = input
+            .char_indices()
+            .nth(err.span().start)
+            .map_or(input.len(), |(i, _)| i);
+
+        let expected = err.expected().map(|c| match c {
+            Some(c) => describe(*c),
+            None => "end of input".to_string(),
+        });
+
+        let message = match err.reason() {
+            SimpleReason::Unexpected => err.label().map(|label| format!("invalid {label}")),
+            SimpleReason::Unclosed { delimiter, .. } => {
+                Some(format!("unclosed delimiter {}", describe(*delimiter)))
+            }
+            SimpleReason::Custom(msg) => Some(msg.clone()),
+        };
+
+        ParseError::new(input, offset, expected, message)
+    }
+
+    /// Converts the error winnow returned for `input`.
+    pub fn from_winnow(input: &str, err: &winnow::error::ParseError<&str, ContextError>) -> Self {
+        let context = err.inner().context().collect_vec();
+
+        let expected = context.iter().filter_map(|c| match c {
+            StrContext::Expected(value) => Some(value.to_string()),
+            _ => None,
+        });
+
+        let label = context.iter().find_map(|c| match c {
+            StrContext::Label(label) => Some(format!("invalid {label}")),
+            _ => None,
+        });
+        let cause = err.inner().cause().map(|cause| cause.to_string());
+        let message = match (label, cause) {
+            (Some(label), Some(cause)) => Some(format!("{label}: {cause}")),
+            (label, cause) => label.or(cause),
+        };
+
+        ParseError::new(input, err.offset(), expected, message)
+    }
+}
+
+fn describe(c: char) -> String {
+    match c {
+        '\n' => "newline".to_string(),
+        c if c.is_control() => format!("`{}`", c.escape_debug()),
+        c => format!("`{c}`"),
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parse error at line {}, column {}",
+            self.line, self.column
+        )?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        writeln!(f)?;
+
+        let found = self.found.map_or("end of input".to_string(), describe);
+        if self.expected.is_empty() {
+            writeln!(f, "found {found}")?;
+        } else {
+            writeln!(f, "expected {}, found {found}", self.expected.join(" or "))?;
+        }
+
+        let gutter = self.line.to_string().len();
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{:gutter$} | {:>col$}", "", "^", col = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use chumsky::prelude::*;
+    use winnow::{ascii::dec_uint, combinator::separated, Parser as _};
+
+    let input = "1,2,3\n4,5,x6\n";
+
+    let errs = text::int::<_, Simple<char>>(10)
+        .separated_by(just(','))
+        .separated_by(just('\n'))
+        .then_ignore(end())
+        .parse(input)
+        .unwrap_err();
+    let err = ParseError::from_chumsky(input, &errs);
+    assert_eq!((err.line, err.column, err.found), (2, 5, Some('x')));
+    assert_eq!(
+        err.to_string(),
+        "parse error at line 2, column 5\n\
+         expected `0`, found `x`\n  \
+          |\n\
+         2 | 4,5,x6\n  \
+          |     ^"
+    );
+
+    let mut parser = separated::<_, u32, Vec<_>, _, _, _, _>(1.., dec_uint, ',');
+    let err = parser.parse("1,2,3\n").unwrap_err();
+    let err = ParseError::from_winnow("1,2,3\n", &err);
+    assert_eq!((err.line, err.column, err.found), (1, 6, Some('\n')));
+    assert_eq!(
+        err.to_string(),
+        "parse error at line 1, column 6\nfound newline\n  |\n1 | 1,2,3\n  |      ^"
+    );
+}
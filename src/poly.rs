@@ -0,0 +1,104 @@
+//! Extrapolating sequences sampled from a polynomial, using Newton forward differences.
+
+use itertools::Itertools;
+use num::{BigInt, Zero};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[error("the differences of {len} samples never reach an all-zero row")]
+pub struct NotPolynomial {
+    pub len: usize,
+}
+
+/// The lowest-degree polynomial through a sequence of samples taken at `x = 0, 1, 2, ...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// The first entry of every difference row above the all-zero one.
+    differences: Vec<BigInt>,
+    len: usize,
+}
+
+impl Polynomial {
+    /// Fits the samples, which must be enough to show an all-zero row of differences, i.e. at
+    /// least `degree + 2` of them.
+    pub fn fit(samples: &[i64]) -> Result<Self, NotPolynomial> {
+        let mut row = samples.iter().map(|&x| BigInt::from(x)).collect_vec();
+        let mut differences = Vec::new();
+
+        while !row.iter().all(Zero::is_zero) {
+            if row.len() == 1 {
+                return Err(NotPolynomial { len: samples.len() });
+            }
+            let next = row.iter().tuple_windows().map(|(x, y)| y - x).collect();
+            differences.push(row.swap_remove(0));
+            row = next;
+        }
+
+        if row.is_empty() {
+            return Err(NotPolynomial { len: samples.len() });
+        }
+
+        Ok(Polynomial {
+            differences,
+            len: samples.len(),
+        })
+    }
+
+    /// The degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.len().checked_sub(1)
+    }
+
+    /// The value at `x`, which may lie outside the samples on either side.
+    pub fn at(&self, x: impl Into<BigInt>) -> BigInt {
+        // f(x) = Σ C(x, i) Δⁱf(0), where every binomial coefficient divides exactly into the next.
+        let x = x.into();
+        let mut binomial = BigInt::from(1);
+        let mut value = BigInt::zero();
+        for (i, diff) in self.differences.iter().enumerate() {
+            value += &binomial * diff;
+            binomial = binomial * (&x - i) / (i + 1);
+        }
+        value
+    }
+
+    /// The value `steps` after the last sample.
+    pub fn ahead(&self, steps: impl Into<BigInt>) -> BigInt {
+        self.at(BigInt::from(self.len) - 1 + steps.into())
+    }
+
+    /// The value `steps` before the first sample.
+    pub fn behind(&self, steps: impl Into<BigInt>) -> BigInt {
+        self.at(-steps.into())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    let squares = Polynomial::fit(&[0, 1, 4, 9, 16]).unwrap();
+    assert_eq!(squares.degree(), Some(2));
+    assert_eq!(squares.ahead(1), 25.into());
+    assert_eq!(squares.ahead(5), 81.into());
+    assert_eq!(squares.behind(3), 9.into());
+    assert_eq!(squares.at(2), 4.into());
+
+    let cubes = Polynomial::fit(&[-8, -1, 0, 1, 8, 27]).unwrap();
+    assert_eq!(cubes.degree(), Some(3));
+    assert_eq!(cubes.behind(1), (-27).into());
+    assert_eq!(cubes.ahead(3_000_000), BigInt::from(3_000_003).pow(3));
+    assert_eq!(
+        cubes.ahead(u64::MAX),
+        (BigInt::from(u64::MAX) + 3u32).pow(3)
+    );
+    assert_eq!(
+        cubes.behind(u64::MAX),
+        (-BigInt::from(u64::MAX) - 2u32).pow(3)
+    );
+
+    assert_eq!(Polynomial::fit(&[0, 0]).unwrap().degree(), None);
+    assert_eq!(Polynomial::fit(&[7, 7]).unwrap().degree(), Some(0));
+    assert!(Polynomial::fit(&[1, 2, 4]).is_err());
+    assert!(Polynomial::fit(&[5]).is_err());
+    assert!(Polynomial::fit(&[]).is_err());
+}
@@ -0,0 +1,115 @@
+//! Drawing grids of connected tiles, such as pipes, for the terminal.
+
+use std::fmt::Write;
+
+use crate::{Dir, Grid, Pos};
+
+/// How a cell stands out from the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    None,
+    /// Part of the path being shown, such as a loop.
+    Path,
+    /// Enclosed by the path.
+    Inside,
+    /// Not enclosed by the path.
+    Outside,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Box-drawing characters, highlighted with ANSI colours.
+    Unicode,
+    /// `|-LJ7F` and friends, with cells inside and outside the path drawn as `I` and `O`.
+    Ascii,
+}
+
+const RESET: &str = "\x1b[0m";
+
+fn mask(dirs: &[Dir]) -> usize {
+    dirs.iter().map(|&dir| 1 << dir as usize).sum()
+}
+
+/// The box-drawing character joining the given directions, indexed by [`mask`].
+const BOX: [char; 16] = [
+    '·', '╵', '╶', '└', '╷', '│', '┌', '├', '╴', '┘', '─', '┴', '┐', '┤', '┬', '┼',
+];
+
+/// The ASCII counterparts of [`BOX`].
+const ASCII: [char; 16] = [
+    '.', '\'', '-', 'L', ',', '|', 'F', '+', '-', 'J', '-', '+', '7', '+', '+', '+',
+];
+
+/// Draws the grid, with every tile shown as the lines joining it to the neighbours it connects to.
+pub fn render<T>(
+    grid: &Grid<T>,
+    connections: impl Fn(&T) -> &[Dir],
+    highlight: impl Fn(Pos) -> Highlight,
+    style: Style,
+) -> String {
+    let mut out = String::new();
+    for (row, tiles) in grid.rows().enumerate() {
+        for (col, tile) in tiles.iter().enumerate() {
+            let dirs = mask(connections(tile));
+            let highlight = highlight(Pos::new(row, col));
+            match style {
+                Style::Unicode => {
+                    let c = BOX[dirs];
+                    let color = match highlight {
+                        Highlight::None => None,
+                        Highlight::Path => Some("\x1b[1;32m"),
+                        Highlight::Inside => Some("\x1b[1;33m"),
+                        Highlight::Outside => Some("\x1b[2m"),
+                    };
+                    match color {
+                        Some(color) => write!(out, "{color}{c}{RESET}").unwrap(),
+                        None => out.push(c),
+                    }
+                }
+                Style::Ascii => out.push(match highlight {
+                    Highlight::Inside => 'I',
+                    Highlight::Outside => 'O',
+                    Highlight::None | Highlight::Path => ASCII[dirs],
+                }),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Dir::*;
+
+    let grid = Grid::from_rows(vec![
+        vec![&[Right, Down][..], &[Right, Left], &[Down, Left]],
+        vec![&[Up, Right], &[Right, Left], &[Up, Left]],
+        vec![&[], &[Up, Right, Down, Left], &[Up]],
+    ])
+    .unwrap();
+    let plain = |_| Highlight::None;
+
+    assert_eq!(
+        render(&grid, |&t| t, plain, Style::Unicode),
+        "┌─┐\n└─┘\n·┼╵\n"
+    );
+    assert_eq!(
+        render(&grid, |&t| t, plain, Style::Ascii),
+        "F-7\nL-J\n.+'\n"
+    );
+
+    let inside = |pos: Pos| match pos.row {
+        2 => Highlight::Outside,
+        _ => Highlight::Path,
+    };
+    assert_eq!(
+        render(&grid, |&t| t, inside, Style::Ascii),
+        "F-7\nL-J\nOOO\n"
+    );
+    assert_eq!(
+        render(&grid, |&t| t, inside, Style::Unicode).lines().nth(2),
+        Some("\x1b[2m·\x1b[0m\x1b[2m┼\x1b[0m\x1b[2m╵\x1b[0m")
+    );
+}
@@ -0,0 +1,305 @@
+//! Shortest path searches over states defined by the caller.
+//!
+//! Each search takes its starting states, a `successors` function and a `goal` predicate, and
+//! returns the cost of the cheapest path to a goal together with the path itself.
+
+use std::{cmp::Ordering, collections::BinaryHeap, collections::VecDeque, hash::Hash, ops::Add};
+
+use num::Zero;
+use rustc_hash::FxHashMap;
+
+/// The cheapest path to a goal, from its start state to the goal state inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+/// Every state reached so far, with the index of the state it was reached from.
+struct Visited<S, C> {
+    nodes: Vec<(S, Option<usize>, C)>,
+    index: FxHashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new() -> Self {
+        Visited {
+            nodes: Vec::new(),
+            index: FxHashMap::default(),
+        }
+    }
+
+    /// Records that `state` can be reached for `cost`, unless it's already known at least as
+    /// cheaply. Returns its index if it was recorded.
+    fn relax(&mut self, state: S, parent: Option<usize>, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        match self.index.get(&state) {
+            Some(&i) if self.nodes[i].2 <= cost => None,
+            Some(&i) => {
+                self.nodes[i].1 = parent;
+                self.nodes[i].2 = cost;
+                Some(i)
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(state.clone(), i);
+                self.nodes.push((state, parent, cost));
+                Some(i)
+            }
+        }
+    }
+
+    fn found(&self, mut i: usize) -> Found<S, C> {
+        let cost = self.nodes[i].2;
+        let mut path = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            path.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+        path.reverse();
+        Found { cost, path }
+    }
+}
+
+/// A state waiting in the priority queue, ordered so that the lowest estimate pops first.
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    node: usize,
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&other.estimate, &other.cost).cmp(&(&self.estimate, &self.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+/// Finds the cheapest path from `start` to a state satisfying `goal`. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_multi([start], successors, goal, |_| C::zero())
+}
+
+/// Like [`dijkstra`], but starting from whichever of `starts` leads to the cheapest path.
+pub fn dijkstra_multi<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_multi(starts, successors, goal, |_| C::zero())
+}
+
+/// Like [`dijkstra`], but guided by `heuristic`, which must never overestimate the remaining cost
+/// to a goal and must be consistent (it drops by at most the cost of each step).
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> C,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_multi([start], successors, goal, heuristic)
+}
+
+/// Like [`astar`], but starting from whichever of `starts` leads to the cheapest path.
+pub fn astar_multi<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut closed = Vec::new();
+    let mut frontier = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(node) = visited.relax(start, None, C::zero()) {
+            frontier.push(Entry {
+                estimate,
+                cost: C::zero(),
+                node,
+            });
+        }
+    }
+
+    while let Some(Entry { cost, node, .. }) = frontier.pop() {
+        closed.resize(visited.nodes.len(), false);
+        if closed[node] || cost > visited.nodes[node].2 {
+            continue;
+        }
+        closed[node] = true;
+
+        let state = visited.nodes[node].0.clone();
+        if goal(&state) {
+            return Some(visited.found(node));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if visited
+                .index
+                .get(&next)
+                .is_some_and(|&i| closed.get(i) == Some(&true))
+            {
+                continue;
+            }
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = visited.relax(next, Some(node), next_cost) {
+                frontier.push(Entry {
+                    estimate,
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the path from `start` to a state satisfying `goal` with the fewest steps.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_multi([start], successors, goal)
+}
+
+/// Like [`bfs`], but starting from whichever of `starts` is closest to a goal.
+pub fn bfs_multi<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut frontier = starts
+        .into_iter()
+        .filter_map(|start| visited.relax(start, None, 0))
+        .collect::<VecDeque<_>>();
+
+    while let Some(node) = frontier.pop_front() {
+        let (state, _, steps) = visited.nodes[node].clone();
+        if goal(&state) {
+            return Some(visited.found(node));
+        }
+
+        for next in successors(&state) {
+            if !visited.index.contains_key(&next) {
+                frontier.extend(visited.relax(next, Some(node), steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every state reachable from `starts`, with the fewest steps needed to reach it.
+pub fn bfs_reach<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> FxHashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = FxHashMap::default();
+    let mut frontier = VecDeque::new();
+    for start in starts {
+        if steps.try_insert(start.clone(), 0).is_ok() {
+            frontier.push_back(start);
+        }
+    }
+
+    while let Some(state) = frontier.pop_front() {
+        let next_steps = steps[&state] + 1;
+        for next in successors(&state) {
+            if steps.try_insert(next.clone(), next_steps).is_ok() {
+                frontier.push_back(next);
+            }
+        }
+    }
+
+    steps
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    // A line of states 0..=10 where each step forward costs 2 and jumping 3 ahead costs 5.
+    let successors = |&n: &u32| {
+        [(n + 1, 2), (n + 3, 5)]
+            .into_iter()
+            .filter(|&(n, _)| n <= 10)
+    };
+
+    let found = dijkstra(0, successors, |&n| n == 10).unwrap();
+    assert_eq!(found.cost, 17);
+    assert_eq!(found.path.len(), 5);
+    assert_eq!((found.path[0], found.path[4]), (0, 10));
+
+    let found = astar(0, successors, |&n| n == 10, |&n| (10 - n) * 5 / 3).unwrap();
+    assert_eq!(found.cost, 17);
+
+    assert_eq!(
+        dijkstra_multi([4, 9], successors, |&n| n == 10)
+            .unwrap()
+            .path,
+        [9, 10]
+    );
+    assert_eq!(dijkstra(0, successors, |&n| n == 11), None);
+
+    let found = bfs(0, |&n| successors(&n).map(|(n, _)| n), |&n| n == 10).unwrap();
+    assert_eq!(found.cost, 4);
+
+    let reach = bfs_reach([0], |&n| successors(&n).map(|(n, _)| n));
+    assert_eq!(reach.len(), 11);
+    assert_eq!(reach[&8], 4);
+}